@@ -1,9 +1,10 @@
-use crate::{
-    graph::{Delaunay, Point},
-    settings::Settings,
-};
+use super::{Statistics, island};
+use crate::settings::{Settings, View};
 use dioxus::prelude::*;
-use rand::{Rng, SeedableRng, rngs::StdRng};
+use rand::{SeedableRng, rngs::StdRng};
+use triangulation_core::{
+    Delaunay, Graph, Point, analysis::Quality, empty_circle::largest_empty_circle,
+};
 
 #[derive(PartialEq, Debug, Clone, Props)]
 pub struct Props {
@@ -13,16 +14,17 @@ pub struct Props {
 #[component]
 pub fn Content(props: Props) -> Element {
     let mut rng = StdRng::seed_from_u64(props.settings.seed);
-    let graph = Delaunay::from(
-        (0..props.settings.num_seeds)
-            .map(|_| Point {
-                x: rng.random_range(0.0..=1.0),
-                y: rng.random_range(0.0..=1.0),
-            })
-            .collect::<Vec<_>>()
-            .into_boxed_slice(),
-    )
-    .unwrap();
+    let points = props
+        .settings
+        .generator()
+        .generate(props.settings.num_seeds as usize, &mut rng);
+    // Degenerate distributions may have no triangulation, their points are still drawn.
+    let graph = Delaunay::from(points.clone().into_boxed_slice()).ok();
+    let quality = graph.as_deref().map(Quality::new);
+    let empty_circle = graph
+        .as_deref()
+        .filter(|_| props.settings.empty_circle)
+        .and_then(largest_empty_circle);
 
     rsx! {
         div {
@@ -30,31 +32,58 @@ pub fn Content(props: Props) -> Element {
             svg {
                 class: "max-w-full max-h-full",
                 view_box: "0 0 1 1",
-                for e in graph.edges() {
-                    if let (Some(v1), Some(v2)) = (e.vertices().0.position(), e.vertices().1.position()) {
-                        line {
-                            x1: v1.x,
-                            y1: v1.y,
-                            x2: v2.x,
-                            y2: v2.y,
-                            stroke: "red",
-                            "stroke-width": "0.003"
-                        }
-                    }
+                if let (View::Map, Some(graph)) = (props.settings.view, &graph) {
+                    {island(graph, &props.settings.map_parameters())}
+                } else {
+                    {triangulation(graph.as_deref(), &points)}
                 }
-                for v in graph.vertices() {
-                    if let Some(p) = v.position() {
-                        circle {
-                            cx: p.x,
-                            cy: p.y,
-                            r: "0.001",
-                            stroke: "black",
-                            "stroke-width": "0.003",
-                            fill: "black"
-                        }
+                if let Some(c) = empty_circle {
+                    circle {
+                        cx: c.center.x,
+                        cy: c.center.y,
+                        r: c.radius,
+                        stroke: "blue",
+                        "stroke-width": "0.003",
+                        fill: "none"
+                    }
+                    circle {
+                        cx: c.center.x,
+                        cy: c.center.y,
+                        r: "0.004",
+                        fill: "blue"
                     }
                 }
             }
         }
+        if let Some(quality) = quality {
+            Statistics { quality }
+        }
+    }
+}
+
+fn triangulation(graph: Option<&Graph>, points: &[Point]) -> Element {
+    rsx! {
+        for e in graph.iter().flat_map(|g| g.edges()) {
+            if let (Some(v1), Some(v2)) = (e.vertices().0.position(), e.vertices().1.position()) {
+                line {
+                    x1: v1.x,
+                    y1: v1.y,
+                    x2: v2.x,
+                    y2: v2.y,
+                    stroke: "red",
+                    "stroke-width": "0.003"
+                }
+            }
+        }
+        for p in points {
+            circle {
+                cx: p.x,
+                cy: p.y,
+                r: "0.001",
+                stroke: "black",
+                "stroke-width": "0.003",
+                fill: "black"
+            }
+        }
     }
 }
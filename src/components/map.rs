@@ -0,0 +1,80 @@
+use dioxus::prelude::*;
+use triangulation_core::{
+    Graph, Point,
+    map::{Biome, Map, Parameters},
+};
+
+const UNIT_SQUARE: [Point; 4] = [
+    Point { x: 0.0, y: 0.0 },
+    Point { x: 1.0, y: 0.0 },
+    Point { x: 1.0, y: 1.0 },
+    Point { x: 0.0, y: 1.0 },
+];
+
+fn color(biome: Biome) -> &'static str {
+    match biome {
+        Biome::Ocean => "#44447a",
+        Biome::Lake => "#336699",
+        Biome::Beach => "#a09077",
+        Biome::Snow => "#ffffff",
+        Biome::Tundra => "#bbbbaa",
+        Biome::Bare => "#888888",
+        Biome::Scorched => "#555555",
+        Biome::Taiga => "#99aa77",
+        Biome::Shrubland => "#889977",
+        Biome::TemperateDesert => "#c9d29b",
+        Biome::TemperateRainForest => "#448855",
+        Biome::TemperateDeciduousForest => "#679459",
+        Biome::Grassland => "#88aa55",
+        Biome::TropicalRainForest => "#337755",
+        Biome::TropicalSeasonalForest => "#559944",
+        Biome::SubtropicalDesert => "#d2b98b",
+    }
+}
+
+/// Cells colored by biome and rivers, to be drawn inside the unit square `svg`.
+pub fn island(graph: &Graph, parameters: &Parameters) -> Element {
+    let map = Map::new(graph, parameters);
+    let cells = graph.vertices().filter_map(|v| {
+        let polygon = v
+            .clipped_voronoi_cell(&UNIT_SQUARE)
+            .iter()
+            .map(|p| format!("{},{}", p.x, p.y))
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!polygon.is_empty()).then(|| (polygon, color(map.cells()[v.id()].biome)))
+    });
+    let rivers = map.rivers().filter_map(|(from, to, flow)| {
+        let (p0, mut p1) = (graph.vertex(from).position()?, graph.vertex(to).position()?);
+        // Rivers end on the coast rather than at the site of the ocean cell.
+        if map.cells()[to].ocean {
+            p1 = Point {
+                x: (p0.x + p1.x) / 2.0,
+                y: (p0.y + p1.y) / 2.0,
+            };
+        }
+        Some((p0, p1, 0.002 * (flow as f64).sqrt()))
+    });
+
+    rsx! {
+        for (polygon, fill) in cells {
+            polygon {
+                points: polygon,
+                fill: fill,
+                stroke: fill,
+                "stroke-width": "0.001"
+            }
+        }
+        for (p0, p1, width) in rivers {
+            line {
+                x1: p0.x,
+                y1: p0.y,
+                x2: p1.x,
+                y2: p1.y,
+                stroke: "#225588",
+                "stroke-width": width,
+                "stroke-linecap": "round"
+            }
+        }
+    }
+}
@@ -1,9 +1,11 @@
 mod content;
 mod header;
 mod input;
+mod map;
 mod mode_selector;
 mod range;
 mod sidebar;
+mod statistics;
 
 pub const MAX_SAFE_INT: u64 = (1 << 53) - 1;
 
@@ -12,5 +14,7 @@ pub use range::Range;
 
 pub use content::Content;
 pub use header::Header;
+pub use map::island;
 pub use mode_selector::{Mode, ModeSelector};
 pub use sidebar::Sidebar;
+pub use statistics::Statistics;
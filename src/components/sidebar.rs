@@ -1,8 +1,9 @@
 use super::*;
-use crate::settings::Settings;
+use crate::settings::{Distribution, Settings, View};
 use dioxus::prelude::*;
 use dioxus_free_icons::icons::fi_icons::FiShuffle;
 use rand::random_range;
+use strum::IntoEnumIterator;
 
 #[derive(PartialEq, Debug, Clone, Props)]
 pub struct Props {
@@ -63,6 +64,170 @@ pub fn Sidebar(props: Props) -> Element {
                                     }
                                 }
                             }
+                            tr {
+                                td {
+                                    class: "p-2",
+                                    "Distribution"
+                                }
+                                td {
+                                    class: "p-2",
+                                    select {
+                                        class: "block p-2.5 w-full text-sm bg-gray-50 rounded-lg border border-gray-300 focus:border-blue-500 focus:outline-none dark:bg-gray-700 dark:border-gray-600",
+                                        onchange: move |e| {
+                                            if let Ok(d) = e.value().parse() {
+                                                settings.write().distribution = d;
+                                            }
+                                        },
+                                        for d in Distribution::iter() {
+                                            option {
+                                                value: <&str>::from(d),
+                                                selected: d == settings().distribution,
+                                                {<&str>::from(d)}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            match settings().distribution {
+                                Distribution::JitteredGrid => rsx! {
+                                    tr {
+                                        td {
+                                            class: "p-2",
+                                            "Jitter (%)"
+                                        }
+                                        td {
+                                            class: "p-2",
+                                            Range {
+                                                min: 0,
+                                                max: 100,
+                                                value: settings().jitter,
+                                                onchange: move |n| settings.write().jitter = n,
+                                            }
+                                        }
+                                    }
+                                },
+                                Distribution::GaussianClusters => rsx! {
+                                    tr {
+                                        td {
+                                            class: "p-2",
+                                            "Clusters"
+                                        }
+                                        td {
+                                            class: "p-2",
+                                            Range {
+                                                min: 1,
+                                                max: 20,
+                                                value: settings().clusters,
+                                                onchange: move |n| settings.write().clusters = n,
+                                            }
+                                        }
+                                    }
+                                    tr {
+                                        td {
+                                            class: "p-2",
+                                            "Deviation (‰)"
+                                        }
+                                        td {
+                                            class: "p-2",
+                                            Range {
+                                                min: 1,
+                                                max: 300,
+                                                value: settings().deviation,
+                                                onchange: move |n| settings.write().deviation = n,
+                                            }
+                                        }
+                                    }
+                                },
+                                Distribution::PoissonDisk => rsx! {
+                                    tr {
+                                        td {
+                                            class: "p-2",
+                                            "Radius (‰)"
+                                        }
+                                        td {
+                                            class: "p-2",
+                                            Range {
+                                                min: 5,
+                                                max: 200,
+                                                value: settings().radius,
+                                                onchange: move |n| settings.write().radius = n,
+                                            }
+                                        }
+                                    }
+                                },
+                                _ => rsx! {},
+                            }
+                            tr {
+                                td {
+                                    class: "p-2",
+                                    "View"
+                                }
+                                td {
+                                    class: "p-2",
+                                    select {
+                                        class: "block p-2.5 w-full text-sm bg-gray-50 rounded-lg border border-gray-300 focus:border-blue-500 focus:outline-none dark:bg-gray-700 dark:border-gray-600",
+                                        onchange: move |e| {
+                                            if let Ok(v) = e.value().parse() {
+                                                settings.write().view = v;
+                                            }
+                                        },
+                                        for v in View::iter() {
+                                            option {
+                                                value: <&str>::from(v),
+                                                selected: v == settings().view,
+                                                {<&str>::from(v)}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            tr {
+                                td {
+                                    class: "p-2",
+                                    "Largest empty circle"
+                                }
+                                td {
+                                    class: "p-2",
+                                    input {
+                                        r#type: "checkbox",
+                                        class: "w-4 h-4 rounded",
+                                        checked: settings().empty_circle,
+                                        onchange: move |e| settings.write().empty_circle = e.checked(),
+                                    }
+                                }
+                            }
+                            if settings().view == View::Map {
+                                tr {
+                                    td {
+                                        class: "p-2",
+                                        "Sea level (%)"
+                                    }
+                                    td {
+                                        class: "p-2",
+                                        Range {
+                                            min: 0,
+                                            max: 50,
+                                            value: settings().sea_level,
+                                            onchange: move |n| settings.write().sea_level = n,
+                                        }
+                                    }
+                                }
+                                tr {
+                                    td {
+                                        class: "p-2",
+                                        "Rivers"
+                                    }
+                                    td {
+                                        class: "p-2",
+                                        Range {
+                                            min: 0,
+                                            max: 100,
+                                            value: settings().rivers,
+                                            onchange: move |n| settings.write().rivers = n,
+                                        }
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -0,0 +1,50 @@
+use dioxus::prelude::*;
+use triangulation_core::analysis::Quality;
+
+#[derive(PartialEq, Debug, Clone, Props)]
+pub struct Props {
+    quality: Quality,
+}
+
+#[component]
+pub fn Statistics(props: Props) -> Element {
+    let quality = props.quality;
+    let histogram = quality.min_angle_histogram;
+    let highest = histogram.counts.iter().copied().max().unwrap_or(0).max(1) as f64;
+    let width = histogram.counts.len() as f64;
+
+    rsx! {
+        div {
+            class: "flex items-center gap-6 p-2 text-sm shrink-0 bg-gray-50 dark:bg-gray-900",
+            div {
+                "Triangles: {quality.faces.len()}"
+            }
+            div {
+                "Min angle: {quality.min_angle.min:.1}° (mean {quality.min_angle.mean:.1}°)"
+            }
+            div {
+                "Aspect ratio: {quality.aspect_ratio.max:.2} (mean {quality.aspect_ratio.mean:.2})"
+            }
+            div {
+                "Radius-edge ratio: {quality.radius_edge_ratio.max:.2} (mean {quality.radius_edge_ratio.mean:.2})"
+            }
+            div {
+                "Degree: {quality.degree.min} to {quality.degree.max} (mean {quality.degree.mean:.2})"
+            }
+            svg {
+                class: "h-8 w-32",
+                view_box: "0 0 {width} 1",
+                preserve_aspect_ratio: "none",
+                for (i, &count) in histogram.counts.iter().enumerate() {
+                    rect {
+                        x: i as f64 + 0.1,
+                        y: 1.0 - count as f64 / highest,
+                        width: 0.8,
+                        height: count as f64 / highest,
+                        fill: "steelblue",
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +0,0 @@
-mod delaunay;
-mod geometry;
-mod graph_datastructure;
-
-pub use delaunay::Delaunay;
-pub use geometry::Point;
@@ -1,5 +1,4 @@
 mod components;
-mod graph;
 mod settings;
 
 pub mod built_info {
@@ -1,7 +1,70 @@
+use triangulation_core::{
+    generator::{
+        Circle, GaussianClusters, Halton, JitteredGrid, Line, PointGenerator, PoissonDisk, Sobol,
+        Uniform,
+    },
+    map::Parameters,
+};
+
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    strum::EnumIter,
+    strum::EnumString,
+    strum::IntoStaticStr,
+)]
+pub enum View {
+    #[default]
+    Triangulation,
+    Map,
+}
+
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    strum::EnumIter,
+    strum::EnumString,
+    strum::IntoStaticStr,
+)]
+pub enum Distribution {
+    #[default]
+    Uniform,
+    #[strum(serialize = "Jittered grid")]
+    JitteredGrid,
+    #[strum(serialize = "Gaussian clusters")]
+    GaussianClusters,
+    Halton,
+    Sobol,
+    #[strum(serialize = "Poisson disk")]
+    PoissonDisk,
+    Circle,
+    Line,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Settings {
     pub seed: u64,
     pub num_seeds: u32,
+    pub distribution: Distribution,
+    // Percent of a grid cell
+    pub jitter: u32,
+    pub clusters: u32,
+    // Thousandths of the unit square
+    pub deviation: u32,
+    pub radius: u32,
+    pub view: View,
+    // Percent of the noise range
+    pub sea_level: u32,
+    pub rivers: u32,
+    pub empty_circle: bool,
 }
 
 impl Default for Settings {
@@ -9,6 +72,47 @@ impl Default for Settings {
         Self {
             seed: 12345,
             num_seeds: 500,
+            distribution: Distribution::default(),
+            jitter: 100,
+            clusters: 5,
+            deviation: 50,
+            radius: 25,
+            view: View::default(),
+            sea_level: 20,
+            rivers: 30,
+            empty_circle: false,
+        }
+    }
+}
+
+impl Settings {
+    pub fn generator(&self) -> Box<dyn PointGenerator> {
+        match self.distribution {
+            Distribution::Uniform => Box::new(Uniform),
+            Distribution::JitteredGrid => Box::new(JitteredGrid {
+                jitter: self.jitter as f64 / 100.0,
+            }),
+            Distribution::GaussianClusters => Box::new(GaussianClusters {
+                clusters: self.clusters as usize,
+                deviation: self.deviation as f64 / 1000.0,
+            }),
+            Distribution::Halton => Box::new(Halton),
+            Distribution::Sobol => Box::new(Sobol),
+            Distribution::PoissonDisk => Box::new(PoissonDisk {
+                radius: self.radius as f64 / 1000.0,
+                ..PoissonDisk::default()
+            }),
+            Distribution::Circle => Box::new(Circle::default()),
+            Distribution::Line => Box::new(Line::default()),
+        }
+    }
+
+    pub fn map_parameters(&self) -> Parameters {
+        Parameters {
+            seed: self.seed,
+            sea_level: self.sea_level as f64 / 100.0,
+            rivers: self.rivers as usize,
+            ..Parameters::default()
         }
     }
 }
@@ -0,0 +1,255 @@
+use crate::{Edge, Face, Graph, Point, Scalar, polygon, polygon::Polygon};
+use alloc::{vec, vec::Vec};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    Exterior,
+    Singular,
+    Regular,
+    Interior,
+}
+
+/// The alpha complex of a Delaunay triangulation, where `alpha` is a radius: faces are kept
+/// when their circumradius is at most `alpha`.
+pub struct AlphaShape<'a, S = f64> {
+    graph: &'a Graph<S>,
+    // Radii are compared squared, as computed, so that a face is kept by its own radius.
+    square_alpha: f64,
+    faces: Vec<bool>,
+}
+
+fn square_circumradius<S: Scalar>(face: Face<'_, S>) -> Option<f64> {
+    let mut vertices = face.vertices();
+    let (p0, p1, p2) = (
+        vertices.next()?.position()?,
+        vertices.next()?.position()?,
+        vertices.next()?.position()?,
+    );
+    Some(Point::square_circumradius(&p0, &p1, &p2))
+}
+
+fn find(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+impl<'a, S: Scalar> AlphaShape<'a, S> {
+    pub fn new(graph: &'a Graph<S>, alpha: f64) -> Self {
+        Self::with_square_alpha(graph, alpha * alpha)
+    }
+
+    fn with_square_alpha(graph: &'a Graph<S>, square_alpha: f64) -> Self {
+        let faces = graph
+            .faces()
+            .map(|face| square_circumradius(face).is_some_and(|r| r <= square_alpha))
+            .collect();
+        AlphaShape {
+            graph,
+            square_alpha,
+            faces,
+        }
+    }
+
+    /// The smallest alpha whose complex is a single connected region covering every point.
+    pub fn optimal(graph: &'a Graph<S>) -> Option<Self> {
+        let mut order = graph
+            .faces()
+            .filter_map(|face| Some((square_circumradius(face)?, face.id())))
+            .collect::<Vec<_>>();
+        order.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut parents = (0..graph.face_count()).collect::<Vec<_>>();
+        let mut added = vec![false; graph.face_count()];
+        let mut covered = vec![false; graph.vertex_count()];
+        let (mut components, mut covered_count) = (0, 0);
+
+        for (radius, id) in order {
+            added[id] = true;
+            components += 1;
+
+            for edge in graph.face(id).edges() {
+                let other = edge.twin().face().id();
+                if added[other] {
+                    let (a, b) = (find(&mut parents, id), find(&mut parents, other));
+                    if a != b {
+                        parents[a] = b;
+                        components -= 1;
+                    }
+                }
+
+                let vertex = edge.vertices().0.id();
+                if !covered[vertex] {
+                    covered[vertex] = true;
+                    covered_count += 1;
+                }
+            }
+
+            if components == 1 && covered_count == graph.vertex_count() - 1 {
+                return Some(AlphaShape::with_square_alpha(graph, radius));
+            }
+        }
+
+        None
+    }
+
+    pub fn alpha(&self) -> f64 {
+        crate::math::sqrt(self.square_alpha)
+    }
+
+    pub fn contains(&self, face: Face<'_, S>) -> bool {
+        self.faces[face.id()]
+    }
+
+    pub fn edge(&self, edge: Edge<'_, S>) -> Classification {
+        match (
+            self.contains(edge.face()),
+            self.contains(edge.twin().face()),
+        ) {
+            (true, true) => Classification::Interior,
+            (true, false) | (false, true) => Classification::Regular,
+            (false, false) if self.is_singular(edge) => Classification::Singular,
+            (false, false) => Classification::Exterior,
+        }
+    }
+
+    // An edge outside every kept face is still part of the complex when its smallest circle
+    // fits within alpha and has no other point inside it.
+    fn is_singular(&self, edge: Edge<'_, S>) -> bool {
+        let (v0, v1) = edge.vertices();
+        let (Some(p0), Some(p1)) = (v0.position(), v1.position()) else {
+            return false;
+        };
+        let (p0, p1) = (p0.to_f64(), p1.to_f64());
+        if p0.distance_squared(&p1) > 4.0 * self.square_alpha {
+            return false;
+        }
+
+        [edge, edge.twin()].into_iter().all(|e| {
+            e.next()
+                .vertices()
+                .1
+                .position()
+                .map(|p| p.to_f64())
+                .is_none_or(|p| (p0.x - p.x) * (p1.x - p.x) + (p0.y - p.y) * (p1.y - p.y) >= 0.0)
+        })
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (Edge<'a, S>, Classification)> + '_ {
+        self.graph.edges().map(|edge| (edge, self.edge(edge)))
+    }
+
+    /// Boundary of the kept faces, as polygons with holes.
+    pub fn polygons(&self) -> Vec<Polygon> {
+        let mut visited = vec![false; self.graph.edge_count()];
+        let mut rings = Vec::new();
+
+        // Only one side of an edge can be on the boundary, so edge ids are enough to track
+        // which ones were traced.
+        for start in (0..self.graph.edge_count() * 2).map(|i| self.graph.edge(i)) {
+            if visited[start.id()] || !self.is_boundary(start) {
+                continue;
+            }
+
+            let mut ring = Vec::new();
+            let mut edge = start;
+            loop {
+                visited[edge.id()] = true;
+                ring.extend(edge.vertices().0.position().map(|p| p.to_f64()));
+
+                // Turn around the destination through kept faces, so that faces touching at a
+                // single vertex are traced as separate rings.
+                edge = edge.next();
+                while !self.is_boundary(edge) {
+                    edge = edge.twin().next();
+                }
+                if edge == start {
+                    break;
+                }
+            }
+            rings.push(ring);
+        }
+
+        polygon::assemble(rings.into_iter())
+    }
+
+    fn is_boundary(&self, edge: Edge<'_, S>) -> bool {
+        self.contains(edge.face()) && !self.contains(edge.twin().face())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    #[test]
+    fn faces_and_edges_by_alpha() {
+        // Right triangle with a circumradius of 2.5 and legs short enough to be singular.
+        let points = [(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)].map(|(x, y)| Point { x, y });
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        let face = delaunay.faces().find(|f| !f.is_infinite()).unwrap();
+
+        assert!(AlphaShape::new(&delaunay, 2.5).contains(face));
+        let shape = AlphaShape::new(&delaunay, 2.4);
+        assert!(!shape.contains(face));
+
+        let mut lengths = shape
+            .edges()
+            .filter_map(|(edge, classification)| {
+                let (v0, v1) = edge.vertices();
+                Some((v0.position()?.distance(&v1.position()?), classification))
+            })
+            .collect::<Vec<_>>();
+        lengths.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let classifications = lengths.into_iter().map(|(_, c)| c).collect::<Vec<_>>();
+        assert_eq!(
+            classifications,
+            [
+                Classification::Singular,
+                Classification::Singular,
+                Classification::Exterior
+            ]
+        );
+    }
+
+    // Kept faces are connected through their edges and touch every vertex.
+    fn connected_and_covering(shape: &AlphaShape) -> bool {
+        let graph = shape.graph;
+        let Some(start) = graph.faces().find(|&f| shape.contains(f)) else {
+            return false;
+        };
+        let mut seen = vec![false; graph.face_count()];
+        let mut stack = vec![start];
+        seen[start.id()] = true;
+        let mut covered = vec![false; graph.vertex_count()];
+        while let Some(face) = stack.pop() {
+            for edge in face.edges() {
+                covered[edge.vertices().0.id()] = true;
+                let other = edge.twin().face();
+                if shape.contains(other) && !seen[other.id()] {
+                    seen[other.id()] = true;
+                    stack.push(other);
+                }
+            }
+        }
+
+        graph.faces().all(|f| !shape.contains(f) || seen[f.id()])
+            && graph
+                .vertices()
+                .all(|v| v.position().is_none() || covered[v.id()])
+    }
+
+    #[test]
+    fn optimal_is_smallest_connected() {
+        let points = Random::new(0x9e37_79b9_7f4a_7c15).points(300);
+        let delaunay = Delaunay::from(points.into()).unwrap();
+
+        let optimal = AlphaShape::optimal(&delaunay).unwrap();
+        assert!(connected_and_covering(&optimal));
+        let smaller = AlphaShape::with_square_alpha(&delaunay, optimal.square_alpha * (1.0 - 1e-9));
+        assert!(!connected_and_covering(&smaller));
+    }
+}
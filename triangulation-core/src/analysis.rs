@@ -0,0 +1,229 @@
+use crate::{Face, Graph, Point, Scalar, math};
+use alloc::{vec, vec::Vec};
+use core::f64::consts::PI;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FaceQuality {
+    pub face: usize,
+    /// Smallest angle, in degrees.
+    pub min_angle: f64,
+    /// Circumradius over twice the inradius, 1 for an equilateral triangle.
+    pub aspect_ratio: f64,
+    pub area: f64,
+    /// Circumradius over the shortest edge.
+    pub radius_edge_ratio: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Summary {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub deviation: f64,
+}
+
+impl Summary {
+    /// Summary of the values in a single pass, without storing them.
+    pub fn new(values: impl Iterator<Item = f64>) -> Self {
+        let (mut count, mut mean, mut squares) = (0.0, 0.0, 0.0);
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+
+        // Welford's running mean and sum of squared deviations.
+        for value in values {
+            count += 1.0;
+            let delta = value - mean;
+            mean += delta / count;
+            squares += delta * (value - mean);
+            min = min.min(value);
+            max = max.max(value);
+        }
+
+        if count == 0.0 {
+            return Summary::default();
+        }
+        Summary {
+            min,
+            max,
+            mean,
+            deviation: math::sqrt(squares / count),
+        }
+    }
+}
+
+/// Counts of values in `counts.len()` bins of equal width from `min` to `max`. Values out of
+/// the range go to the first or last bin.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    pub min: f64,
+    pub max: f64,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    pub fn new(values: impl Iterator<Item = f64>, min: f64, max: f64, bins: usize) -> Self {
+        let mut counts = vec![0; bins];
+        for value in values {
+            let bin = (value - min) / (max - min) * bins as f64;
+            if let Some(count) = counts.get_mut((bin.max(0.0) as usize).min(bins.saturating_sub(1)))
+            {
+                *count += 1;
+            }
+        }
+        Histogram { min, max, counts }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quality {
+    /// Finite faces, leaving out the degenerate ones whose measures are not finite.
+    pub faces: Vec<FaceQuality>,
+    pub min_angle: Summary,
+    pub aspect_ratio: Summary,
+    pub area: Summary,
+    pub radius_edge_ratio: Summary,
+    /// Minimum angles in 5 degree bins, from 0 to 60 degrees.
+    pub min_angle_histogram: Histogram,
+    pub degree: Summary,
+    /// Number of vertices by count of finite neighbors.
+    pub degrees: Vec<usize>,
+}
+
+fn face_quality<S: Scalar>(face: Face<'_, S>) -> Option<FaceQuality> {
+    let mut vertices = face.vertices().map(|v| v.position().map(|p| p.to_f64()));
+    let corners: [Point; 3] = [vertices.next()??, vertices.next()??, vertices.next()??];
+
+    // The ratios are measured on the face scaled to a longest edge of 1, so that the products
+    // of lengths neither overflow nor underflow.
+    let scale = (0..3)
+        .map(|i| corners[i].distance(&corners[(i + 1) % 3]))
+        .fold(0.0, f64::max);
+    let corners = corners.map(|p| Point {
+        x: (p.x - corners[0].x) / scale,
+        y: (p.y - corners[0].y) / scale,
+    });
+
+    let lengths: [f64; 3] =
+        core::array::from_fn(|i| corners[(i + 1) % 3].distance(&corners[(i + 2) % 3]));
+    let (a, b, c) = (lengths[0], lengths[1], lengths[2]);
+    let (p1, p2) = (&corners[1], &corners[2]);
+    let area = (p1.x * p2.y - p2.x * p1.y).abs() / 2.0;
+
+    let circumradius = a * b * c / (4.0 * area);
+    let inradius = 2.0 * area / (a + b + c);
+    let shortest = a.min(b).min(c);
+
+    // The smallest angle faces the shortest edge.
+    let (u, v) = if shortest == a {
+        (b, c)
+    } else if shortest == b {
+        (a, c)
+    } else {
+        (a, b)
+    };
+    let min_angle = math::atan2(2.0 * area, (u * u + v * v - shortest * shortest) / 2.0);
+
+    let quality = FaceQuality {
+        face: face.id(),
+        min_angle: min_angle * 180.0 / PI,
+        aspect_ratio: circumradius / (2.0 * inradius),
+        area: area * scale * scale,
+        radius_edge_ratio: circumradius / shortest,
+    };
+    // Flat faces, or ones too small or too large for the arithmetic, have no finite measures.
+    [
+        quality.min_angle,
+        quality.aspect_ratio,
+        quality.area,
+        quality.radius_edge_ratio,
+    ]
+    .iter()
+    .all(|v| v.is_finite())
+    .then_some(quality)
+}
+
+impl Quality {
+    pub fn new<S: Scalar>(graph: &Graph<S>) -> Self {
+        let faces = graph.faces().filter_map(face_quality).collect::<Vec<_>>();
+
+        let degree = graph
+            .vertices()
+            .filter(|v| v.position().is_some())
+            .map(|v| v.neighbors().filter(|n| n.position().is_some()).count())
+            .collect::<Vec<_>>();
+        let mut degrees = vec![0; degree.iter().max().map_or(0, |d| d + 1)];
+        for &d in &degree {
+            degrees[d] += 1;
+        }
+
+        Quality {
+            min_angle: Summary::new(faces.iter().map(|f| f.min_angle)),
+            aspect_ratio: Summary::new(faces.iter().map(|f| f.aspect_ratio)),
+            area: Summary::new(faces.iter().map(|f| f.area)),
+            radius_edge_ratio: Summary::new(faces.iter().map(|f| f.radius_edge_ratio)),
+            min_angle_histogram: Histogram::new(faces.iter().map(|f| f.min_angle), 0.0, 60.0, 12),
+            degree: Summary::new(degree.iter().map(|&d| d as f64)),
+            degrees,
+            faces,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+    use alloc::boxed::Box;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn equilateral() {
+        let height = math::sqrt(3.0) / 2.0;
+        let points = [(0.0, 0.0), (1.0, 0.0), (0.5, height)].map(|(x, y)| Point { x, y });
+        let quality = Quality::new(&Delaunay::from(Box::new(points)).unwrap());
+
+        assert_eq!(quality.faces.len(), 1);
+        let face = quality.faces[0];
+        assert!(close(face.min_angle, 60.0));
+        assert!(close(face.aspect_ratio, 1.0));
+        assert!(close(face.area, height / 2.0));
+        assert!(close(face.radius_edge_ratio, 1.0 / math::sqrt(3.0)));
+        assert_eq!(quality.min_angle_histogram.counts[11], 1);
+        assert_eq!(quality.degrees, [0, 0, 3]);
+        assert_eq!(quality.degree.deviation, 0.0);
+    }
+
+    #[test]
+    fn random() {
+        // The products of three lengths underflow at the smaller scale.
+        for scale in [1.0, 1e-150] {
+            let points = Random::new(0xbb67_ae85_84ca_a73b)
+                .points(300)
+                .into_iter()
+                .map(|p| Point {
+                    x: p.x * scale,
+                    y: p.y * scale,
+                })
+                .collect::<Box<[_]>>();
+            let delaunay = Delaunay::from(points).unwrap();
+            let quality = Quality::new(&delaunay);
+
+            let finite = delaunay.faces().filter(|f| !f.is_infinite()).count();
+            assert_eq!(quality.faces.len(), finite);
+            assert!(quality.min_angle.min > 0.0 && quality.min_angle.max <= 60.0 + 1e-9);
+            assert!(quality.aspect_ratio.min >= 1.0 - 1e-9);
+            assert!(quality.radius_edge_ratio.min >= 1.0 / math::sqrt(3.0) - 1e-9);
+            assert!(quality.area.min > 0.0 && quality.area.max.is_finite());
+            let counts = &quality.min_angle_histogram.counts;
+            assert_eq!(counts.iter().sum::<usize>(), finite);
+            let vertices = delaunay.vertices().filter(|v| v.position().is_some());
+            assert_eq!(quality.degrees.iter().sum::<usize>(), vertices.count());
+        }
+    }
+}
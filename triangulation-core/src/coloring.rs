@@ -0,0 +1,301 @@
+use crate::{Graph, Scalar};
+use alloc::{collections::BTreeSet, collections::VecDeque, vec, vec::Vec};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strategy {
+    /// First free color, in order of id.
+    #[default]
+    Greedy,
+    /// First free color, picking next the element with the most distinct colors around it.
+    DSatur,
+    /// At most five colors for vertices, recoloring Kempe chains as needed, and three for
+    /// faces, coloring them in smallest-last order.
+    Bounded,
+}
+
+// Finite vertices or faces by id, with their finite neighbors.
+struct Adjacency {
+    elements: Vec<usize>,
+    neighbors: Vec<Vec<usize>>,
+}
+
+// Neighbors of every vertex are in order around it.
+fn vertex_adjacency<S: Scalar>(graph: &Graph<S>) -> Adjacency {
+    Adjacency {
+        elements: graph
+            .vertices()
+            .filter(|v| v.position().is_some())
+            .map(|v| v.id())
+            .collect(),
+        neighbors: graph
+            .vertices()
+            .map(|v| {
+                v.neighbors()
+                    .filter(|n| n.position().is_some())
+                    .map(|n| n.id())
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+fn face_adjacency<S: Scalar>(graph: &Graph<S>) -> Adjacency {
+    Adjacency {
+        elements: graph
+            .faces()
+            .filter(|f| !f.is_infinite())
+            .map(|f| f.id())
+            .collect(),
+        neighbors: graph
+            .faces()
+            .map(|f| {
+                f.edges()
+                    .map(|e| e.twin().face())
+                    .filter(|n| !n.is_infinite())
+                    .map(|n| n.id())
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+fn free_color(adjacency: &Adjacency, colors: &[Option<usize>], i: usize) -> usize {
+    let used = adjacency.neighbors[i]
+        .iter()
+        .filter_map(|&j| colors[j])
+        .collect::<BTreeSet<_>>();
+    (0..).find(|c| !used.contains(c)).unwrap()
+}
+
+fn greedy(adjacency: &Adjacency, order: impl Iterator<Item = usize>) -> Vec<Option<usize>> {
+    let mut colors = vec![None; adjacency.neighbors.len()];
+    for i in order {
+        colors[i] = Some(free_color(adjacency, &colors, i));
+    }
+    colors
+}
+
+fn dsatur(adjacency: &Adjacency) -> Vec<Option<usize>> {
+    let neighbors = &adjacency.neighbors;
+    let mut colors = vec![None; neighbors.len()];
+    let mut saturation = vec![BTreeSet::new(); neighbors.len()];
+    // Uncolored elements by saturation, then degree.
+    let mut open = adjacency
+        .elements
+        .iter()
+        .map(|&i| (0, neighbors[i].len(), i))
+        .collect::<BTreeSet<_>>();
+
+    while let Some((_, _, i)) = open.pop_last() {
+        let color = free_color(adjacency, &colors, i);
+        colors[i] = Some(color);
+
+        for &j in &neighbors[i] {
+            if colors[j].is_none() && !saturation[j].contains(&color) {
+                open.remove(&(saturation[j].len(), neighbors[j].len(), j));
+                saturation[j].insert(color);
+                open.insert((saturation[j].len(), neighbors[j].len(), j));
+            }
+        }
+    }
+    colors
+}
+
+// Elements in the reverse of the order of removing one of smallest degree at a time, so that
+// each one has at most the degeneracy of the graph neighbors before it.
+fn smallest_last(adjacency: &Adjacency) -> Vec<usize> {
+    let mut degrees = adjacency.neighbors.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut open = adjacency
+        .elements
+        .iter()
+        .map(|&i| (degrees[i], i))
+        .collect::<BTreeSet<_>>();
+    let mut removed = vec![false; degrees.len()];
+    let mut order = Vec::with_capacity(open.len());
+
+    while let Some((_, i)) = open.pop_first() {
+        removed[i] = true;
+        order.push(i);
+        for &j in &adjacency.neighbors[i] {
+            if !removed[j] {
+                open.remove(&(degrees[j], j));
+                degrees[j] -= 1;
+                open.insert((degrees[j], j));
+            }
+        }
+    }
+    order.reverse();
+    order
+}
+
+// Five coloring of a planar graph whose adjacency lists follow the embedding.
+fn five_color(adjacency: &Adjacency) -> Vec<Option<usize>> {
+    let mut colors = vec![None; adjacency.neighbors.len()];
+
+    for i in smallest_last(adjacency) {
+        let color = free_color(adjacency, &colors, i);
+        if color < 5 {
+            colors[i] = Some(color);
+            continue;
+        }
+
+        // The vertex has five colored neighbors, all of different colors. The chain of the
+        // colors of two non-consecutive ones either doesn't link them, and swapping its colors
+        // frees one, or separates the two others in the plane.
+        let neighbors = adjacency.neighbors[i]
+            .iter()
+            .copied()
+            .filter(|&j| colors[j].is_some())
+            .collect::<Vec<_>>();
+        colors[i] = [(0, 2), (1, 3)]
+            .into_iter()
+            .find_map(|(a, b)| {
+                let (start, end) = (neighbors[a], neighbors[b]);
+                let (ca, cb) = (colors[start]?, colors[end]?);
+
+                let mut chain = vec![start];
+                let mut seen = BTreeSet::from([start]);
+                let mut queue = VecDeque::from([start]);
+                while let Some(j) = queue.pop_front() {
+                    for &k in &adjacency.neighbors[j] {
+                        if (colors[k] == Some(ca) || colors[k] == Some(cb)) && seen.insert(k) {
+                            chain.push(k);
+                            queue.push_back(k);
+                        }
+                    }
+                }
+                if seen.contains(&end) {
+                    return None;
+                }
+
+                for &j in &chain {
+                    colors[j] = Some(if colors[j] == Some(ca) { cb } else { ca });
+                }
+                Some(ca)
+            })
+            .or(Some(color));
+    }
+    colors
+}
+
+fn color(
+    adjacency: &Adjacency,
+    strategy: Strategy,
+    bounded: fn(&Adjacency) -> Vec<Option<usize>>,
+) -> Vec<Option<usize>> {
+    match strategy {
+        Strategy::Greedy => greedy(adjacency, adjacency.elements.iter().copied()),
+        Strategy::DSatur => dsatur(adjacency),
+        Strategy::Bounded => bounded(adjacency),
+    }
+}
+
+/// Color of every finite vertex, different from those of its neighbors, so that no two
+/// adjacent Voronoi cells share a color.
+pub fn color_vertices<S: Scalar>(graph: &Graph<S>, strategy: Strategy) -> Vec<Option<usize>> {
+    color(&vertex_adjacency(graph), strategy, five_color)
+}
+
+/// Color of every finite face, different from those of the faces sharing an edge with it.
+pub fn color_faces<S: Scalar>(graph: &Graph<S>, strategy: Strategy) -> Vec<Option<usize>> {
+    color(&face_adjacency(graph), strategy, |adjacency| {
+        greedy(adjacency, smallest_last(adjacency).into_iter())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    const STRATEGIES: [Strategy; 3] = [Strategy::Greedy, Strategy::DSatur, Strategy::Bounded];
+
+    fn count(colors: &[Option<usize>]) -> usize {
+        colors.iter().flatten().collect::<BTreeSet<_>>().len()
+    }
+
+    #[test]
+    fn vertices() {
+        for seed in 0..5 {
+            let points = Random::new(seed).points(500);
+            let delaunay = Delaunay::from(points.into()).unwrap();
+
+            for strategy in STRATEGIES {
+                let colors = color_vertices(&delaunay, strategy);
+                for vertex in delaunay.vertices() {
+                    let color = colors[vertex.id()];
+                    assert_eq!(color.is_some(), vertex.position().is_some());
+                    for neighbor in vertex.neighbors() {
+                        assert!(color.is_none() || colors[neighbor.id()] != color);
+                    }
+                }
+                if strategy == Strategy::Bounded {
+                    assert!(count(&colors) <= 5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn faces() {
+        for seed in 0..5 {
+            let points = Random::new(seed).points(500);
+            let delaunay = Delaunay::from(points.into()).unwrap();
+
+            for strategy in STRATEGIES {
+                let colors = color_faces(&delaunay, strategy);
+                for face in delaunay.faces() {
+                    let color = colors[face.id()];
+                    assert_eq!(color.is_some(), !face.is_infinite());
+                    for edge in face.edges() {
+                        let neighbor = edge.twin().face();
+                        assert!(color.is_none() || colors[neighbor.id()] != color);
+                    }
+                }
+                if strategy == Strategy::Bounded {
+                    assert!(count(&colors) <= 3);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn icosahedron() {
+        // Top, five upper and five lower vertices alternating around, and bottom, with every
+        // vertex having five neighbors in order around it.
+        let (top, bottom) = (0, 11);
+        let upper = |k: usize| 1 + k % 5;
+        let lower = |k: usize| 6 + k % 5;
+        let mut neighbors = vec![(0..5).map(upper).collect::<Vec<_>>()];
+        for k in 5..10 {
+            neighbors.push(vec![
+                top,
+                upper(k + 1),
+                lower(k),
+                lower(k - 1),
+                upper(k - 1),
+            ]);
+        }
+        for k in 5..10 {
+            neighbors.push(vec![
+                upper(k),
+                upper(k + 1),
+                lower(k + 1),
+                bottom,
+                lower(k - 1),
+            ]);
+        }
+        neighbors.push((0..5).map(lower).rev().collect());
+        let adjacency = Adjacency {
+            elements: (0..12).collect(),
+            neighbors,
+        };
+
+        let colors = five_color(&adjacency);
+        assert!(count(&colors) <= 5);
+        for (i, neighbors) in adjacency.neighbors.iter().enumerate() {
+            assert!(colors[i].is_some());
+            assert!(neighbors.iter().all(|&j| colors[j] != colors[i]));
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{Edge, Face, Graph, Point, Scalar, polygon};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::fmt;
+
+pub use crate::polygon::Polygon;
+
+#[derive(Debug, Clone)]
+pub struct Polyline {
+    pub points: Vec<Point>,
+    pub closed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Node {
+    Vertex(usize),
+    Crossing(usize, usize),
+}
+
+#[derive(Clone, Copy)]
+struct Corner {
+    node: Node,
+    point: Point,
+    value: f64,
+}
+
+type Segment = (Corner, Corner);
+
+fn crossing<S: Scalar>(
+    values: &[f64],
+    edge: Edge<'_, S>,
+    level: f64,
+    index: usize,
+) -> Option<Corner> {
+    let (v0, v1) = edge.vertices();
+    // Always interpolate from the vertex with the lowest id so that both faces sharing the
+    // edge agree on the exact position.
+    let (v0, v1) = if v0.id() < v1.id() {
+        (v0, v1)
+    } else {
+        (v1, v0)
+    };
+    let (p0, p1) = (v0.position()?.to_f64(), v1.position()?.to_f64());
+    let (z0, z1) = (values[v0.point_index()?], values[v1.point_index()?]);
+    let t = (level - z0) / (z1 - z0);
+
+    Some(Corner {
+        node: Node::Crossing(edge.id(), index),
+        point: Point {
+            x: p0.x + t * (p1.x - p0.x),
+            y: p0.y + t * (p1.y - p0.y),
+        },
+        value: level,
+    })
+}
+
+// Corners of a finite face, each with the edge leading to the next corner.
+fn corners<'a, S: Scalar>(
+    values: &[f64],
+    face: Face<'a, S>,
+) -> Option<Vec<(Corner, Option<Edge<'a, S>>)>> {
+    face.edges()
+        .map(|edge| {
+            let vertex = edge.vertices().0;
+            let corner = Corner {
+                node: Node::Vertex(vertex.id()),
+                point: vertex.position()?.to_f64(),
+                value: values[vertex.point_index()?],
+            };
+            Some((corner, Some(edge)))
+        })
+        .collect()
+}
+
+// Keeps the part of the polygon where `inside` holds. Sides created along the level line
+// have no edge.
+fn clip<'a, S: Scalar>(
+    values: &[f64],
+    polygon: &[(Corner, Option<Edge<'a, S>>)],
+    level: (f64, usize),
+    inside: impl Fn(f64) -> bool,
+) -> Option<Vec<(Corner, Option<Edge<'a, S>>)>> {
+    let mut clipped = Vec::with_capacity(polygon.len() + 2);
+
+    for (i, &(corner, edge)) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()].0;
+
+        match (inside(corner.value), inside(next.value)) {
+            (true, true) => clipped.push((corner, edge)),
+            (true, false) => {
+                clipped.push((corner, edge));
+                clipped.push((crossing(values, edge?, level.0, level.1)?, None));
+            }
+            (false, true) => clipped.push((crossing(values, edge?, level.0, level.1)?, edge)),
+            (false, false) => {}
+        }
+    }
+
+    Some(clipped)
+}
+
+fn stitch(segments: &[Segment]) -> Vec<Polyline> {
+    let mut starts = BTreeMap::<Node, Vec<usize>>::new();
+    let mut ends = BTreeMap::<Node, usize>::new();
+    for (i, (start, end)) in segments.iter().enumerate() {
+        starts.entry(start.node).or_default().push(i);
+        *ends.entry(end.node).or_default() += 1;
+    }
+
+    let mut used = vec![false; segments.len()];
+    let mut polylines = Vec::new();
+
+    // Open polylines first, starting where no segment ends, then the remaining rings.
+    let open = (0..segments.len()).filter(|&i| !ends.contains_key(&segments[i].0.node));
+    let order = open.chain(0..segments.len()).collect::<Vec<_>>();
+
+    for first in order {
+        if used[first] {
+            continue;
+        }
+
+        let mut points = vec![segments[first].0.point];
+        let mut current = first;
+        let closed = loop {
+            used[current] = true;
+            let end = segments[current].1;
+            points.push(end.point);
+
+            let next = starts
+                .get(&end.node)
+                .and_then(|candidates| candidates.iter().copied().find(|&i| !used[i]));
+
+            match next {
+                Some(next) => current = next,
+                None => break end.node == segments[first].0.node,
+            }
+        };
+
+        if closed {
+            points.pop();
+        }
+        polylines.push(Polyline { points, closed });
+    }
+
+    polylines
+}
+
+fn isoline<S: Scalar>(graph: &Graph<S>, values: &[f64], level: f64) -> Vec<Polyline> {
+    let mut segments = Vec::new();
+
+    for face in graph.faces().filter(|f| !f.is_infinite()) {
+        let Some(corners) = corners(values, face) else {
+            continue;
+        };
+
+        let mut start = None;
+        let mut end = None;
+        for (i, &(corner, edge)) in corners.iter().enumerate() {
+            let next = corners[(i + 1) % corners.len()].0;
+            match (corner.value >= level, next.value >= level) {
+                (true, false) => start = edge.and_then(|e| crossing(values, e, level, 0)),
+                (false, true) => end = edge.and_then(|e| crossing(values, e, level, 0)),
+                _ => {}
+            }
+        }
+
+        if let (Some(start), Some(end)) = (start, end) {
+            segments.push((start, end));
+        }
+    }
+
+    stitch(&segments)
+}
+
+fn isoband<S: Scalar>(
+    graph: &Graph<S>,
+    values: &[f64],
+    lower: (f64, usize),
+    upper: (f64, usize),
+) -> Vec<Polygon> {
+    let mut segments = Vec::new();
+
+    for face in graph.faces().filter(|f| !f.is_infinite()) {
+        let Some(polygon) = corners(values, face)
+            .and_then(|p| clip(values, &p, lower, |v| v >= lower.0))
+            .and_then(|p| clip(values, &p, upper, |v| v < upper.0))
+        else {
+            continue;
+        };
+
+        for (i, &(corner, edge)) in polygon.iter().enumerate() {
+            let next = polygon[(i + 1) % polygon.len()].0;
+            if edge.is_none_or(|e| e.twin().face().is_infinite()) {
+                segments.push((corner, next));
+            }
+        }
+    }
+
+    polygon::assemble(
+        stitch(&segments)
+            .into_iter()
+            .map(|polyline| polyline.points),
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContourError {
+    ValueCount,
+}
+
+impl fmt::Display for ContourError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContourError::ValueCount => write!(f, "exactly one value per point is required"),
+        }
+    }
+}
+
+impl core::error::Error for ContourError {}
+
+fn check<S: Scalar>(graph: &Graph<S>, values: &[f64]) -> Result<(), ContourError> {
+    if values.len() == graph.points().len() {
+        Ok(())
+    } else {
+        Err(ContourError::ValueCount)
+    }
+}
+
+/// Isolines of the `values` given for every input point, one set of polylines per level.
+pub fn isolines<S: Scalar>(
+    graph: &Graph<S>,
+    values: &[f64],
+    levels: &[f64],
+) -> Result<Vec<Vec<Polyline>>, ContourError> {
+    check(graph, values)?;
+    Ok(levels
+        .iter()
+        .map(|&level| isoline(graph, values, level))
+        .collect())
+}
+
+/// Filled regions between consecutive levels, where `levels[i] <= value < levels[i + 1]`.
+pub fn isobands<S: Scalar>(
+    graph: &Graph<S>,
+    values: &[f64],
+    levels: &[f64],
+) -> Result<Vec<Vec<Polygon>>, ContourError> {
+    check(graph, values)?;
+    Ok(levels
+        .windows(2)
+        .enumerate()
+        .map(|(i, pair)| isoband(graph, values, (pair[0], i), (pair[1], i + 1)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    fn area(polygon: &Polygon) -> f64 {
+        polygon::signed_area(&polygon.exterior).abs()
+            - polygon
+                .holes
+                .iter()
+                .map(|hole| polygon::signed_area(hole).abs())
+                .sum::<f64>()
+    }
+
+    #[test]
+    fn radial_field() {
+        let center = Point { x: 0.5, y: 0.5 };
+        let mut points = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]
+            .map(|(x, y)| Point { x, y })
+            .to_vec();
+        points.extend(Random::new(0x3c6e_f372_fe94_f82b).points(2000));
+        let values = points
+            .iter()
+            .map(|p| p.distance(&center))
+            .collect::<Vec<_>>();
+        let delaunay = Delaunay::from(points.into()).unwrap();
+
+        let lines = isolines(&delaunay, &values, &[0.3]).unwrap();
+        assert_eq!(lines[0].len(), 1);
+        let ring = &lines[0][0];
+        assert!(ring.closed);
+        assert!(
+            ring.points
+                .iter()
+                .all(|p| (p.distance(&center) - 0.3).abs() < 0.01)
+        );
+        let inside = polygon::signed_area(&ring.points).abs();
+        assert!((inside / (core::f64::consts::PI * 0.09) - 1.0).abs() < 0.02);
+
+        // The bands below 0.3 tile the inside of the ring.
+        let bands = isobands(&delaunay, &values, &[0.0, 0.1, 0.2, 0.3]).unwrap();
+        assert_eq!(bands.len(), 3);
+        assert!(bands[0].iter().all(|p| p.holes.is_empty()));
+        assert!(bands[1..].iter().flatten().all(|p| p.holes.len() == 1));
+        let total = bands.iter().flatten().map(area).sum::<f64>();
+        assert!((total - inside).abs() < 1e-9);
+    }
+
+    #[test]
+    fn value_count() {
+        let points = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)].map(|(x, y)| Point { x, y });
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        assert_eq!(
+            isolines(&delaunay, &[0.0], &[0.5]).err(),
+            Some(ContourError::ValueCount)
+        );
+        assert_eq!(
+            isobands(&delaunay, &[0.0; 4], &[0.0, 0.5]).err(),
+            Some(ContourError::ValueCount)
+        );
+    }
+}
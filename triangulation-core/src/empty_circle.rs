@@ -0,0 +1,207 @@
+use crate::{Graph, Point, Scalar, polygon};
+use alloc::vec::Vec;
+
+/// Circle with no vertex of the triangulation strictly inside.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct EmptyCircle {
+    pub center: Point,
+    pub radius: f64,
+}
+
+/// Largest empty circle centered at `point`, reaching the vertex closest to it.
+pub fn empty_circle_at<S: Scalar>(graph: &Graph<S>, point: &Point<S>) -> Option<EmptyCircle> {
+    let site = graph.nearest(point)?.position()?;
+    Some(EmptyCircle {
+        center: point.to_f64(),
+        radius: site.distance(point),
+    })
+}
+
+/// Largest empty circle centered in the convex hull, the place farthest from every vertex.
+pub fn largest_empty_circle<S: Scalar>(graph: &Graph<S>) -> Option<EmptyCircle> {
+    let hull = graph
+        .hull()
+        .filter_map(|v| v.position())
+        .collect::<Vec<_>>();
+    largest_empty_circle_in(graph, &hull)
+}
+
+// Parameter along `p + t * d` of its crossing with the segment from `a` to `b`.
+fn crossing(p: &Point, d: &Point, a: &Point, b: &Point) -> Option<f64> {
+    let (ex, ey) = (b.x - a.x, b.y - a.y);
+    let denominator = d.x * ey - d.y * ex;
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let (fx, fy) = (a.x - p.x, a.y - p.y);
+    let t = (fx * ey - fy * ex) / denominator;
+    let s = (fx * d.y - fy * d.x) / denominator;
+    (0.0..=1.0).contains(&s).then_some(t)
+}
+
+/// Largest empty circle centered in the simple polygon `region`. The center is a Voronoi
+/// vertex, a crossing of a Voronoi edge with the boundary, or a corner of the region.
+pub fn largest_empty_circle_in<S: Scalar>(
+    graph: &Graph<S>,
+    region: &[Point<S>],
+) -> Option<EmptyCircle> {
+    let ring = region.iter().map(|p| p.to_f64()).collect::<Vec<_>>();
+    let mut best: Option<EmptyCircle> = None;
+    // A candidate that can't be measured, for a corner or site that is not finite, is left out
+    // rather than failing the whole query.
+    let mut consider = |center: Point, radius: f64| {
+        if !radius.is_nan() && best.is_none_or(|b| radius > b.radius) {
+            best = Some(EmptyCircle { center, radius });
+        }
+    };
+
+    for face in graph.faces() {
+        let site = face.vertices().next().and_then(|v| v.position());
+        let (Some(center), Some(site)) = (face.circumcenter(), site) else {
+            continue;
+        };
+        if polygon::contains(&ring, &center) {
+            consider(center, center.distance(&site.to_f64()));
+        }
+    }
+
+    // Voronoi edges go between the centers of the two faces of a Delaunay edge, or away from
+    // the hull to infinity.
+    for edge in graph.edges() {
+        let (v0, v1) = edge.vertices();
+        let (Some(p0), Some(p1)) = (v0.position(), v1.position()) else {
+            continue;
+        };
+        let (p0, p1) = (p0.to_f64(), p1.to_f64());
+
+        let (start, direction, end) = match (edge.face(), edge.twin().face()) {
+            (f0, f1) if !f0.is_infinite() && !f1.is_infinite() => {
+                let (Some(c0), Some(c1)) = (f0.circumcenter(), f1.circumcenter()) else {
+                    continue;
+                };
+                let direction = Point {
+                    x: c1.x - c0.x,
+                    y: c1.y - c0.y,
+                };
+                (c0, direction, 1.0)
+            }
+            (f0, f1) => {
+                let finite = if f0.is_infinite() { f1 } else { f0 };
+                let opposite = finite
+                    .vertices()
+                    .find(|&v| v != v0 && v != v1)
+                    .and_then(|v| v.position());
+                let (Some(center), Some(opposite)) = (finite.circumcenter(), opposite) else {
+                    continue;
+                };
+                let opposite = opposite.to_f64();
+                let mut direction = Point {
+                    x: p0.y - p1.y,
+                    y: p1.x - p0.x,
+                };
+                if direction.x * (opposite.x - p0.x) + direction.y * (opposite.y - p0.y) > 0.0 {
+                    direction = Point {
+                        x: -direction.x,
+                        y: -direction.y,
+                    };
+                }
+                (center, direction, f64::INFINITY)
+            }
+        };
+
+        for i in 0..ring.len() {
+            let (a, b) = (&ring[i], &ring[(i + 1) % ring.len()]);
+            let t = crossing(&start, &direction, a, b).filter(|t| (0.0..=end).contains(t));
+            if let Some(t) = t {
+                let center = Point {
+                    x: start.x + t * direction.x,
+                    y: start.y + t * direction.y,
+                };
+                consider(center, center.distance(&p0));
+            }
+        }
+    }
+
+    for circle in region
+        .iter()
+        .filter_map(|corner| empty_circle_at(graph, corner))
+    {
+        consider(circle.center, circle.radius);
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    // Distance from `center` to the closest point.
+    fn clearance(points: &[Point], center: &Point) -> f64 {
+        points
+            .iter()
+            .map(|p| p.distance(center))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn brute_force() {
+        let points = Random::new(0x428a_2f98_d728_ae22).points(40);
+        let delaunay = Delaunay::from(points.clone().into()).unwrap();
+        let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(x, y)| Point { x, y });
+        let circle = largest_empty_circle_in(&delaunay, &square).unwrap();
+
+        // In the square or on its boundary.
+        let inside = |v: f64| (-1e-12..=1.0 + 1e-12).contains(&v);
+        assert!(inside(circle.center.x) && inside(circle.center.y));
+        assert!((clearance(&points, &circle.center) - circle.radius).abs() < 1e-12);
+
+        // No center of a fine grid is farther from the points, and the grid center next to the
+        // best one is less than a spacing closer.
+        let steps = 400;
+        let spacing = 1.0 / steps as f64;
+        let grid = (0..=steps)
+            .flat_map(|i| {
+                (0..=steps).map(move |j| Point {
+                    x: i as f64 * spacing,
+                    y: j as f64 * spacing,
+                })
+            })
+            .map(|center| clearance(&points, &center))
+            .fold(0.0, f64::max);
+        assert!(grid <= circle.radius + 1e-12);
+        assert!(grid >= circle.radius - spacing);
+    }
+
+    #[test]
+    fn hull() {
+        let points = Random::new(0x7137_4491_23ef_65cd).points(60);
+        let delaunay = Delaunay::from(points.clone().into()).unwrap();
+        let circle = largest_empty_circle(&delaunay).unwrap();
+        assert!((clearance(&points, &circle.center) - circle.radius).abs() < 1e-12);
+
+        let at = empty_circle_at(&delaunay, &points[0]).unwrap();
+        assert_eq!(at.radius, 0.0);
+    }
+
+    #[test]
+    fn skipped_corner() {
+        let points = Random::new(0xb5c0_fbcf_ec4d_3b2f).points(40);
+        let delaunay = Delaunay::from(points.clone().into()).unwrap();
+        let corners = [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (f64::NAN, 0.5),
+            (1.0, 1.0),
+            (0.0, 1.0),
+        ];
+        let region = corners.map(|(x, y)| Point { x, y });
+
+        let circle = largest_empty_circle_in(&delaunay, &region).unwrap();
+        assert!(circle.radius.is_finite());
+        assert!((clearance(&points, &circle.center) - circle.radius).abs() < 1e-12);
+    }
+}
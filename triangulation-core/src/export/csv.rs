@@ -0,0 +1,97 @@
+use crate::{Attributes, Graph, Scalar, graph::write_csv_string};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Result, Write};
+
+// Attribute names are escaped like values, and suffixed with underscores when they would
+// collide with a built-in column or an earlier one.
+fn header<W: Write>(out: &mut W, columns: &str, attributes: &Attributes) -> Result {
+    write!(out, "{columns}")?;
+
+    let mut used = columns.split(',').map(String::from).collect::<Vec<_>>();
+    for name in attributes.names() {
+        let mut name = String::from(name);
+        while used.contains(&name) {
+            name.push('_');
+        }
+
+        write!(out, ",")?;
+        write_csv_string(out, &name)?;
+        used.push(name);
+    }
+    writeln!(out)
+}
+
+fn values<W: Write>(out: &mut W, attributes: &Attributes, index: usize) -> Result {
+    for (_, value) in attributes.values(index) {
+        write!(out, ",")?;
+        value.write_csv(out)?;
+    }
+    writeln!(out)
+}
+
+pub fn vertices<S: Scalar, W: Write>(graph: &Graph<S>, out: &mut W) -> Result {
+    header(out, "id,x,y", graph.vertex_attributes())?;
+
+    for vertex in graph.vertices() {
+        if let Some(p) = vertex.position() {
+            write!(out, "{},{},{}", vertex.id(), p.x, p.y)?;
+            values(out, graph.vertex_attributes(), vertex.id())?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn edges<S: Scalar, W: Write>(graph: &Graph<S>, out: &mut W) -> Result {
+    header(out, "id,v0,v1", graph.edge_attributes())?;
+
+    for edge in graph.edges() {
+        let (v0, v1) = edge.vertices();
+        if v0.position().is_some() && v1.position().is_some() {
+            write!(out, "{},{},{}", edge.id(), v0.id(), v1.id())?;
+            values(out, graph.edge_attributes(), edge.id())?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn faces<S: Scalar, W: Write>(graph: &Graph<S>, out: &mut W) -> Result {
+    header(out, "id,v0,v1,v2", graph.face_attributes())?;
+
+    for face in graph.faces() {
+        if face.vertices().all(|v| v.position().is_some()) {
+            write!(out, "{}", face.id())?;
+            for vertex in face.vertices() {
+                write!(out, ",{}", vertex.id())?;
+            }
+            values(out, graph.face_attributes(), face.id())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, Point};
+    use alloc::vec;
+
+    #[test]
+    fn attribute_names_in_header() {
+        let points = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)].map(|(x, y)| Point { x, y });
+        let mut delaunay = Delaunay::from(points.into()).unwrap();
+        let count = delaunay.vertex_count();
+        let mut attributes = delaunay.attributes_mut();
+        let attributes = attributes.vertices();
+        attributes.insert("id", vec![0u32; count]);
+        attributes.insert("id_", vec![1u32; count]);
+        attributes.insert("a,\"b\"", vec![2u32; count]);
+
+        let mut out = String::new();
+        vertices(&delaunay, &mut out).unwrap();
+        assert_eq!(out.lines().next(), Some("id,x,y,id_,id__,\"a,\"\"b\"\"\""));
+        assert!(out.lines().skip(1).all(|line| line.ends_with(",0,1,2")));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Attributes, Graph, Point, Scalar, graph::write_json_string};
+use alloc::{vec, vec::Vec};
+use core::fmt::{Result, Write};
+
+enum Geometry {
+    Point(Point),
+    LineString(Vec<Point>),
+    Polygon(Vec<Point>),
+}
+
+fn coordinates<W: Write>(out: &mut W, points: &[Point]) -> Result {
+    write!(out, "[")?;
+    for (i, p) in points.iter().enumerate() {
+        if i > 0 {
+            write!(out, ",")?;
+        }
+        write!(out, "[{},{}]", p.x, p.y)?;
+    }
+    write!(out, "]")
+}
+
+impl Geometry {
+    fn write<W: Write>(&self, out: &mut W) -> Result {
+        match self {
+            Geometry::Point(p) => {
+                write!(out, r#"{{"type":"Point","coordinates":[{},{}]}}"#, p.x, p.y)
+            }
+            Geometry::LineString(points) => {
+                write!(out, r#"{{"type":"LineString","coordinates":"#)?;
+                coordinates(out, points)?;
+                write!(out, "}}")
+            }
+            Geometry::Polygon(ring) => {
+                write!(out, r#"{{"type":"Polygon","coordinates":["#)?;
+                coordinates(out, ring)?;
+                write!(out, "]}}")
+            }
+        }
+    }
+}
+
+fn collection<W: Write, I>(out: &mut W, attributes: &Attributes, features: I) -> Result
+where
+    I: Iterator<Item = (usize, Geometry)>,
+{
+    write!(out, r#"{{"type":"FeatureCollection","features":["#)?;
+    for (i, (id, geometry)) in features.enumerate() {
+        if i > 0 {
+            write!(out, ",")?;
+        }
+        write!(out, r#"{{"type":"Feature","properties":{{"id":{id}"#)?;
+        for (name, value) in attributes.values(id) {
+            write!(out, ",")?;
+            write_json_string(out, name)?;
+            write!(out, ":")?;
+            value.write_json(out)?;
+        }
+        write!(out, r#"}},"geometry":"#)?;
+        geometry.write(out)?;
+        write!(out, "}}")?;
+    }
+    writeln!(out, "]}}")
+}
+
+pub fn vertices<S: Scalar, W: Write>(graph: &Graph<S>, out: &mut W) -> Result {
+    collection(
+        out,
+        graph.vertex_attributes(),
+        graph
+            .vertices()
+            .filter_map(|v| v.position().map(|p| (v.id(), Geometry::Point(p.to_f64())))),
+    )
+}
+
+pub fn edges<S: Scalar, W: Write>(graph: &Graph<S>, out: &mut W) -> Result {
+    collection(
+        out,
+        graph.edge_attributes(),
+        graph.edges().filter_map(|e| {
+            let (v0, v1) = e.vertices();
+            Some((
+                e.id(),
+                Geometry::LineString(vec![v0.position()?.to_f64(), v1.position()?.to_f64()]),
+            ))
+        }),
+    )
+}
+
+pub fn faces<S: Scalar, W: Write>(graph: &Graph<S>, out: &mut W) -> Result {
+    collection(
+        out,
+        graph.face_attributes(),
+        graph.faces().filter_map(|f| {
+            let mut ring = f
+                .vertices()
+                .map(|v| v.position().map(|p| p.to_f64()))
+                .collect::<Option<Vec<_>>>()?;
+            ring.push(ring[0]);
+            Some((f.id(), Geometry::Polygon(ring)))
+        }),
+    )
+}
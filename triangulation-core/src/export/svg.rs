@@ -0,0 +1,57 @@
+use crate::{Graph, Scalar};
+use core::fmt::{Result, Write};
+
+pub fn write<S: Scalar, W: Write>(graph: &Graph<S>, out: &mut W) -> Result {
+    let (min_x, min_y, max_x, max_y) = graph
+        .vertices()
+        .filter_map(|v| v.position())
+        .map(|p| p.to_f64())
+        .fold(
+            (
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(min_x, min_y, max_x, max_y), p| {
+                (
+                    min_x.min(p.x),
+                    min_y.min(p.y),
+                    max_x.max(p.x),
+                    max_y.max(p.y),
+                )
+            },
+        );
+    let stroke_width = (max_x - min_x).max(max_y - min_y) * 0.003;
+
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+        min_x,
+        min_y,
+        max_x - min_x,
+        max_y - min_y
+    )?;
+
+    for edge in graph.edges() {
+        if let (Some(v1), Some(v2)) = (edge.vertices().0.position(), edge.vertices().1.position()) {
+            writeln!(
+                out,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="red" stroke-width="{stroke_width}"/>"#,
+                v1.x, v1.y, v2.x, v2.y
+            )?;
+        }
+    }
+
+    for vertex in graph.vertices() {
+        if let Some(p) = vertex.position() {
+            writeln!(
+                out,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="black"/>"#,
+                p.x, p.y, stroke_width
+            )?;
+        }
+    }
+
+    writeln!(out, "</svg>")
+}
@@ -0,0 +1,410 @@
+use crate::{Point, math};
+use alloc::{vec, vec::Vec};
+use core::{
+    array,
+    f64::consts::{PI, SQRT_2},
+};
+use rand::{Rng, RngCore};
+
+/// Distribution of points in the unit square. The same generator and random number generator
+/// state always give the same points.
+pub trait PointGenerator {
+    fn generate(&self, count: usize, rng: &mut dyn RngCore) -> Vec<Point>;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Uniform;
+
+impl PointGenerator for Uniform {
+    fn generate(&self, count: usize, rng: &mut dyn RngCore) -> Vec<Point> {
+        (0..count)
+            .map(|_| Point {
+                x: rng.random_range(0.0..=1.0),
+                y: rng.random_range(0.0..=1.0),
+            })
+            .collect()
+    }
+}
+
+/// One point per cell of a square grid, moved randomly by up to `jitter` times the cell size.
+#[derive(Debug, Clone, Copy)]
+pub struct JitteredGrid {
+    pub jitter: f64,
+}
+
+impl Default for JitteredGrid {
+    fn default() -> Self {
+        JitteredGrid { jitter: 1.0 }
+    }
+}
+
+impl PointGenerator for JitteredGrid {
+    fn generate(&self, count: usize, rng: &mut dyn RngCore) -> Vec<Point> {
+        let columns = (1..).find(|c| c * c >= count).unwrap_or(1);
+        let rows = count.div_ceil(columns);
+
+        (0..count)
+            .map(|i| {
+                let (column, row) = ((i % columns) as f64, (i / columns) as f64);
+                Point {
+                    x: (column + 0.5 + self.jitter * (rng.random::<f64>() - 0.5)) / columns as f64,
+                    y: (row + 0.5 + self.jitter * (rng.random::<f64>() - 0.5)) / rows as f64,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Points normally distributed around `clusters` uniform centers. The ones falling outside of
+/// the unit square are drawn again, and clamped into it after `ATTEMPTS` tries.
+#[derive(Debug, Clone, Copy)]
+pub struct GaussianClusters {
+    pub clusters: usize,
+    pub deviation: f64,
+}
+
+impl Default for GaussianClusters {
+    fn default() -> Self {
+        GaussianClusters {
+            clusters: 5,
+            deviation: 0.05,
+        }
+    }
+}
+
+impl GaussianClusters {
+    pub const ATTEMPTS: usize = 100;
+}
+
+impl PointGenerator for GaussianClusters {
+    fn generate(&self, count: usize, rng: &mut dyn RngCore) -> Vec<Point> {
+        let centers = Uniform.generate(self.clusters.max(1), rng);
+        let mut sample = || {
+            let center = centers[rng.random_range(0..centers.len())];
+            // Box-Muller transform
+            let radius = self.deviation * math::sqrt(-2.0 * math::ln(1.0 - rng.random::<f64>()));
+            let angle = 2.0 * PI * rng.random::<f64>();
+            Point {
+                x: center.x + radius * math::cos(angle),
+                y: center.y + radius * math::sin(angle),
+            }
+        };
+        let inside = |p: &Point| (0.0..1.0).contains(&p.x) && (0.0..1.0).contains(&p.y);
+        // Up to the largest value below 1, with NaN from an invalid deviation at 0.
+        let clamp = |v: f64| {
+            if v.is_nan() {
+                0.0
+            } else {
+                v.clamp(0.0, 1.0 - f64::EPSILON / 2.0)
+            }
+        };
+
+        (0..count)
+            .map(|_| {
+                let mut point = sample();
+                for _ in 1..Self::ATTEMPTS {
+                    if inside(&point) {
+                        break;
+                    }
+                    point = sample();
+                }
+                Point {
+                    x: clamp(point.x),
+                    y: clamp(point.y),
+                }
+            })
+            .collect()
+    }
+}
+
+fn radical_inverse(mut i: u64, base: u64) -> f64 {
+    let (mut value, mut scale) = (0.0, 1.0);
+    while i > 0 {
+        scale /= base as f64;
+        value += (i % base) as f64 * scale;
+        i /= base;
+    }
+    value
+}
+
+/// Halton sequence in bases 2 and 3, shifted by a random offset modulo 1.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Halton;
+
+impl PointGenerator for Halton {
+    fn generate(&self, count: usize, rng: &mut dyn RngCore) -> Vec<Point> {
+        let (dx, dy) = (rng.random::<f64>(), rng.random::<f64>());
+        let wrap = |v: f64| if v >= 1.0 { v - 1.0 } else { v };
+
+        (1..=count as u64)
+            .map(|i| Point {
+                x: wrap(radical_inverse(i, 2) + dx),
+                y: wrap(radical_inverse(i, 3) + dy),
+            })
+            .collect()
+    }
+}
+
+/// Two dimensional Sobol sequence, scrambled by a random digital shift. The sequence has at
+/// most `u32::MAX` points.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sobol;
+
+impl PointGenerator for Sobol {
+    fn generate(&self, count: usize, rng: &mut dyn RngCore) -> Vec<Point> {
+        // Direction numbers of the first dimension are the plain binary fractions, the ones of
+        // the second follow from the primitive polynomial x + 1.
+        let first: [u32; 32] = array::from_fn(|bit| 1 << (31 - bit));
+        let mut m = 1u32;
+        let second: [u32; 32] = array::from_fn(|bit| {
+            let direction = m << (31 - bit);
+            m ^= m << 1;
+            direction
+        });
+
+        let (mut x, mut y) = (rng.next_u32(), rng.next_u32());
+        let scale = 1.0 / (1u64 << 32) as f64;
+
+        (0..count.min(u32::MAX as usize) as u32)
+            .map(|i| {
+                // Gray code order: flip the direction of the lowest zero bit of the index.
+                let bit = (!i).trailing_zeros() as usize;
+                x ^= first[bit];
+                y ^= second[bit];
+                Point {
+                    x: x as f64 * scale,
+                    y: y as f64 * scale,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Poisson-disk sampling by Bridson's algorithm: no two points closer than `radius`, stopping
+/// at `count` points or when no more fit. A radius that is not finite gives no points, one
+/// that is not positive gives `Uniform` points.
+#[derive(Debug, Clone, Copy)]
+pub struct PoissonDisk {
+    pub radius: f64,
+    pub attempts: usize,
+}
+
+impl Default for PoissonDisk {
+    fn default() -> Self {
+        PoissonDisk {
+            radius: 0.02,
+            attempts: 30,
+        }
+    }
+}
+
+impl PointGenerator for PoissonDisk {
+    fn generate(&self, count: usize, rng: &mut dyn RngCore) -> Vec<Point> {
+        if count == 0 || !self.radius.is_finite() {
+            return Vec::new();
+        }
+        if self.radius <= 0.0 {
+            return Uniform.generate(count, rng);
+        }
+
+        // Cells small enough to hold at most one point each, but no more cells than about four
+        // per point, so that a tiny radius does not allocate a huge grid. Larger cells chain
+        // their points through `next`.
+        let limit = (1..).find(|&c| c * c >= 4 * count).unwrap_or(1);
+        let cells = math::ceil(SQRT_2 / self.radius).clamp(1.0, limit as f64) as usize;
+        let size = 1.0 / cells as f64;
+        let mut grid = vec![None; cells * cells];
+        let mut next = Vec::new();
+        let cell = |p: &Point| {
+            let clamp = |v: f64| ((v / size) as usize).min(cells - 1);
+            (clamp(p.x), clamp(p.y))
+        };
+
+        let mut points = Uniform.generate(1, rng);
+        let mut active = vec![0];
+        let (cx, cy) = cell(&points[0]);
+        grid[cy * cells + cx] = Some(0);
+        next.push(None);
+
+        while points.len() < count && !active.is_empty() {
+            let index = rng.random_range(0..active.len());
+            let center = points[active[index]];
+
+            let candidate = (0..self.attempts).find_map(|_| {
+                let radius = self.radius * (1.0 + rng.random::<f64>());
+                let angle = 2.0 * PI * rng.random::<f64>();
+                let p = Point {
+                    x: center.x + radius * math::cos(angle),
+                    y: center.y + radius * math::sin(angle),
+                };
+                if !(0.0..1.0).contains(&p.x) || !(0.0..1.0).contains(&p.y) {
+                    return None;
+                }
+
+                let (cx, cy) = cell(&p);
+                let free = (cy.saturating_sub(2)..(cy + 3).min(cells)).all(|y| {
+                    (cx.saturating_sub(2)..(cx + 3).min(cells)).all(|x| {
+                        let mut chain = grid[y * cells + x];
+                        while let Some(i) = chain {
+                            if points[i].distance_squared(&p) < self.radius * self.radius {
+                                return false;
+                            }
+                            chain = next[i];
+                        }
+                        true
+                    })
+                });
+                free.then_some((p, cx, cy))
+            });
+
+            match candidate {
+                Some((p, cx, cy)) => {
+                    next.push(grid[cy * cells + cx].replace(points.len()));
+                    active.push(points.len());
+                    points.push(p);
+                }
+                None => {
+                    active.swap_remove(index);
+                }
+            }
+        }
+
+        points
+    }
+}
+
+/// Cocircular points, a degenerate case for the triangulation.
+#[derive(Debug, Clone, Copy)]
+pub struct Circle {
+    pub center: Point,
+    pub radius: f64,
+}
+
+impl Default for Circle {
+    fn default() -> Self {
+        Circle {
+            center: Point { x: 0.5, y: 0.5 },
+            radius: 0.4,
+        }
+    }
+}
+
+impl PointGenerator for Circle {
+    fn generate(&self, count: usize, rng: &mut dyn RngCore) -> Vec<Point> {
+        (0..count)
+            .map(|_| {
+                let angle = 2.0 * PI * rng.random::<f64>();
+                Point {
+                    x: self.center.x + self.radius * math::cos(angle),
+                    y: self.center.y + self.radius * math::sin(angle),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Collinear points, a degenerate case for the triangulation.
+#[derive(Debug, Clone, Copy)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Default for Line {
+    fn default() -> Self {
+        Line {
+            start: Point { x: 0.1, y: 0.1 },
+            end: Point { x: 0.9, y: 0.9 },
+        }
+    }
+}
+
+impl PointGenerator for Line {
+    fn generate(&self, count: usize, rng: &mut dyn RngCore) -> Vec<Point> {
+        (0..count)
+            .map(|_| {
+                let t = rng.random::<f64>();
+                Point {
+                    x: self.start.x + t * (self.end.x - self.start.x),
+                    y: self.start.y + t * (self.end.y - self.start.y),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Random;
+    use alloc::{boxed::Box, vec};
+
+    fn generators() -> Vec<Box<dyn PointGenerator>> {
+        vec![
+            Box::new(Uniform),
+            Box::new(JitteredGrid::default()),
+            Box::new(GaussianClusters::default()),
+            Box::new(Halton),
+            Box::new(Sobol),
+            Box::new(PoissonDisk::default()),
+            Box::new(Circle::default()),
+            Box::new(Line::default()),
+        ]
+    }
+
+    #[test]
+    fn count_bounds_and_determinism() {
+        for generator in generators() {
+            let points = generator.generate(500, &mut Random::new(0x3c6e_f372_fe94_f82b));
+            assert_eq!(points.len(), 500);
+            assert!(
+                points
+                    .iter()
+                    .all(|p| (0.0..=1.0).contains(&p.x) && (0.0..=1.0).contains(&p.y))
+            );
+
+            let again = generator.generate(500, &mut Random::new(0x3c6e_f372_fe94_f82b));
+            assert!(
+                points
+                    .iter()
+                    .zip(&again)
+                    .all(|(a, b)| a.x.to_bits() == b.x.to_bits() && a.y.to_bits() == b.y.to_bits())
+            );
+        }
+    }
+
+    #[test]
+    fn poisson_disk_distance() {
+        let generator = PoissonDisk {
+            radius: 0.05,
+            attempts: 30,
+        };
+        // Far more points than fit, so the sampling stops when the square is full.
+        let points = generator.generate(10_000, &mut Random::new(0xa54f_f53a_5f1d_36f1));
+        assert!(points.len() > 100 && points.len() < 10_000);
+        for (i, a) in points.iter().enumerate() {
+            for b in &points[..i] {
+                assert!(a.distance_squared(b) >= 0.05 * 0.05);
+            }
+        }
+    }
+
+    #[test]
+    fn poisson_disk_radius() {
+        let mut rng = Random::new(0x1f83_d9ab_fb41_bd6b);
+        for radius in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let generator = PoissonDisk {
+                radius,
+                attempts: 30,
+            };
+            assert!(generator.generate(10, &mut rng).is_empty());
+        }
+
+        // A tiny radius keeps the grid to a few cells per point, which then hold several.
+        let generator = PoissonDisk {
+            radius: 1e-300,
+            attempts: 30,
+        };
+        assert_eq!(generator.generate(1000, &mut rng).len(), 1000);
+    }
+}
@@ -0,0 +1,195 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    any::Any,
+    fmt::{self, Write},
+};
+
+pub trait Attribute: 'static {
+    fn write_csv(&self, out: &mut dyn Write) -> fmt::Result;
+    fn write_json(&self, out: &mut dyn Write) -> fmt::Result;
+}
+
+pub(crate) fn write_csv_string(out: &mut dyn Write, value: &str) -> fmt::Result {
+    if value.contains([',', '"', '\n', '\r']) {
+        write!(out, "\"{}\"", value.replace('"', "\"\""))
+    } else {
+        write!(out, "{value}")
+    }
+}
+
+pub(crate) fn write_json_string(out: &mut dyn Write, value: &str) -> fmt::Result {
+    write!(out, "\"")?;
+    for c in value.chars() {
+        match c {
+            '"' => write!(out, "\\\"")?,
+            '\\' => write!(out, "\\\\")?,
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32)?,
+            c => write!(out, "{c}")?,
+        }
+    }
+    write!(out, "\"")
+}
+
+macro_rules! integer_attribute {
+    ($($t:ty),*) => {
+        $(
+            impl Attribute for $t {
+                fn write_csv(&self, out: &mut dyn Write) -> fmt::Result {
+                    write!(out, "{self}")
+                }
+
+                fn write_json(&self, out: &mut dyn Write) -> fmt::Result {
+                    write!(out, "{self}")
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float_attribute {
+    ($($t:ty),*) => {
+        $(
+            impl Attribute for $t {
+                fn write_csv(&self, out: &mut dyn Write) -> fmt::Result {
+                    write!(out, "{self}")
+                }
+
+                fn write_json(&self, out: &mut dyn Write) -> fmt::Result {
+                    if self.is_finite() {
+                        write!(out, "{self}")
+                    } else {
+                        write!(out, "null")
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_attribute!(bool, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+float_attribute!(f32, f64);
+
+impl Attribute for String {
+    fn write_csv(&self, out: &mut dyn Write) -> fmt::Result {
+        write_csv_string(out, self)
+    }
+
+    fn write_json(&self, out: &mut dyn Write) -> fmt::Result {
+        write_json_string(out, self)
+    }
+}
+
+trait Column {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn value(&self, index: usize) -> &dyn Attribute;
+    fn remap(&self, map: &[Option<usize>]) -> Box<dyn Column>;
+}
+
+impl<T: Attribute + Clone + Default> Column for Vec<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn value(&self, index: usize) -> &dyn Attribute {
+        &self[index]
+    }
+
+    fn remap(&self, map: &[Option<usize>]) -> Box<dyn Column> {
+        let values = map
+            .iter()
+            .map(|i| i.map_or_else(T::default, |i| self[i].clone()))
+            .collect::<Vec<_>>();
+        Box::new(values)
+    }
+}
+
+/// Named columns holding one value per vertex, edge or face.
+pub struct Attributes {
+    len: usize,
+    columns: Vec<(String, Box<dyn Column>)>,
+}
+
+impl Attributes {
+    pub(crate) fn new(len: usize) -> Self {
+        Attributes {
+            len,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Adds or replaces the column `name`. Returns false, leaving the columns as they were,
+    /// unless there is one value per element. Elements created when the graph is rebuilt get
+    /// the default value.
+    pub fn insert<T: Attribute + Clone + Default>(&mut self, name: &str, values: Vec<T>) -> bool {
+        if values.len() != self.len {
+            return false;
+        }
+        let column: Box<dyn Column> = Box::new(values);
+
+        match self.columns.iter_mut().find(|(n, _)| n == name) {
+            Some((_, existing)) => *existing = column,
+            None => self.columns.push((name.into(), column)),
+        }
+        true
+    }
+
+    // Columns for `map.len()` elements, each taking the value of the element `map` gives or
+    // the default one.
+    pub(crate) fn remap(&self, map: &[Option<usize>]) -> Attributes {
+        Attributes {
+            len: map.len(),
+            columns: self
+                .columns
+                .iter()
+                .map(|(name, column)| (name.clone(), column.remap(map)))
+                .collect(),
+        }
+    }
+
+    pub fn values(&self, index: usize) -> impl Iterator<Item = (&str, &dyn Attribute)> {
+        self.columns
+            .iter()
+            .map(move |(name, column)| (name.as_str(), column.value(index)))
+    }
+
+    pub fn get<T: Attribute>(&self, name: &str) -> Option<&[T]> {
+        self.columns
+            .iter()
+            .find(|(n, _)| n == name)
+            .and_then(|(_, column)| column.as_any().downcast_ref::<Vec<T>>())
+            .map(|values| values.as_slice())
+    }
+
+    pub fn get_mut<T: Attribute>(&mut self, name: &str) -> Option<&mut [T]> {
+        self.columns
+            .iter_mut()
+            .find(|(n, _)| n == name)
+            .and_then(|(_, column)| column.as_any_mut().downcast_mut::<Vec<T>>())
+            .map(|values| values.as_mut_slice())
+    }
+
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.columns.len();
+        self.columns.retain(|(n, _)| n != name);
+        self.columns.len() != len
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.columns.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+}
+
+impl fmt::Debug for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
@@ -0,0 +1,297 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::ops::Deref;
+
+use super::{
+    delaunay::{Error, legalize, triangulate},
+    geometry::Point,
+    graph_datastructure::{AttributesMut, Graph, Vertex, graph},
+    scalar::Scalar,
+};
+
+/// Farthest-point Delaunay triangulation: the circle through the vertices of every face holds
+/// all the points. Only the points of the convex hull are vertices, and the Voronoi cells of
+/// the graph are the farthest-point Voronoi regions.
+#[derive(Debug)]
+pub struct Farthest<S = f64> {
+    graph: Graph<S>,
+}
+
+impl<S> Deref for Farthest<S> {
+    type Target = Graph<S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.graph
+    }
+}
+
+// Same determinant as `Scalar::in_circle`, positive when `p` is outside of the circle. Points
+// within rounding of the circle count as on it, otherwise both diagonals of cocircular points
+// could be found illegal and flipped forever.
+fn outside_circle(p: Point, p0: Point, p1: Point, p2: Point) -> bool {
+    let (dx, dy) = (p0.x - p.x, p0.y - p.y);
+    let (ex, ey) = (p1.x - p.x, p1.y - p.y);
+    let (fx, fy) = (p2.x - p.x, p2.y - p.y);
+    let (ap, bp, cp) = (dx * dx + dy * dy, ex * ex + ey * ey, fx * fx + fy * fy);
+
+    let determinant =
+        dx * (ey * cp - bp * fy) - dy * (ex * cp - bp * fx) + ap * (ex * fy - ey * fx);
+    let magnitude = dx.abs() * ((ey * cp).abs() + (bp * fy).abs())
+        + dy.abs() * ((ex * cp).abs() + (bp * fx).abs())
+        + ap * ((ex * fy).abs() + (ey * fx).abs());
+    determinant > 1e-12 * magnitude
+}
+
+// Indices of the corners of the convex hull, leaving out points on its edges.
+fn convex_hull<S: Scalar>(points: &[Point<S>]) -> Vec<usize> {
+    let mut sorted = (0..points.len()).collect::<Vec<_>>();
+    sorted.sort_by(|&a, &b| {
+        (points[a].x, points[a].y)
+            .partial_cmp(&(points[b].x, points[b].y))
+            .unwrap()
+    });
+
+    // Lower then upper chain, both turning the same way.
+    let mut hull: Vec<usize> = Vec::with_capacity(points.len() + 1);
+    for chain in [sorted.clone(), sorted.into_iter().rev().collect()] {
+        let start = hull.len();
+        for i in chain {
+            while hull.len() >= start + 2
+                && !Point::is_ccw(
+                    &points[hull[hull.len() - 2]],
+                    &points[hull[hull.len() - 1]],
+                    &points[i],
+                )
+            {
+                hull.pop();
+            }
+            hull.push(i);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+impl<S: Scalar> Farthest<S> {
+    pub fn from(points: Box<[Point<S>]>) -> Result<Self, Error> {
+        if points.len() < 3 {
+            return Err(Error::NotEnoughPoints);
+        }
+
+        let corners = convex_hull(&points);
+        let subset = corners.iter().map(|&i| points[i]).collect::<Vec<_>>();
+        if subset.len() < 3 {
+            return Err(Error::Collinear);
+        }
+
+        // The edge between `pa` and `pb` is illegal when `p0` is outside of the circle through
+        // the three others, where `Delaunay` flips it when inside.
+        let illegal = |p0: usize, pa: usize, pb: usize, p1: usize| {
+            outside_circle(
+                subset[p0].to_f64(),
+                subset[pa].to_f64(),
+                subset[pb].to_f64(),
+                subset[p1].to_f64(),
+            )
+        };
+        let (mut edges, mut faces, mut vertices) = triangulate(&subset, &illegal)?;
+
+        // The points are in convex position, so every edge can be flipped and flipping until
+        // none is illegal ends like for `Regular`.
+        loop {
+            let mut flipped = false;
+            for t0e0 in 0..edges.len() {
+                let (va, vb) = (edges[t0e0].vertex, edges[t0e0 ^ 1].vertex);
+                let v0 = edges[edges[t0e0 ^ 1].next].vertex;
+                let v1 = edges[edges[t0e0].next].vertex;
+                if [va, vb, v0, v1].contains(&0) {
+                    continue;
+                }
+
+                let [p0, pa, pb, p1] = [v0, va, vb, v1].map(|v| vertices[v].position.unwrap());
+                if illegal(p0, pa, pb, p1) {
+                    legalize(&mut edges, &mut faces, &mut vertices, &illegal, t0e0);
+                    flipped = true;
+                }
+            }
+            if !flipped {
+                break;
+            }
+        }
+
+        for vertex in vertices.iter_mut() {
+            vertex.position = vertex.position.map(|i| corners[i]);
+        }
+
+        Ok(Farthest {
+            graph: graph(
+                points,
+                edges.into_boxed_slice(),
+                faces.into_boxed_slice(),
+                vertices.into_boxed_slice(),
+            ),
+        })
+    }
+
+    pub fn attributes_mut(&mut self) -> AttributesMut<'_, S> {
+        self.graph.attributes_mut()
+    }
+
+    /// Vertex farthest from `point`, the one whose farthest-point region contains it, found by
+    /// walking to farther neighbors. A region is the intersection of the half-planes farther
+    /// from its vertex than from each neighbor, so a vertex with no farther neighbor is it.
+    pub fn farthest(&self, point: &Point<S>) -> Option<Vertex<'_, S>> {
+        let distance = |v: Vertex<'_, S>| v.position().map(|p| p.distance_squared(point));
+        let mut vertex = self.vertices().find(|v| v.position().is_some())?;
+
+        while let Some(farther) = vertex
+            .neighbors()
+            .filter(|n| n.position().is_some())
+            .find(|&n| distance(n) > distance(vertex))
+        {
+            vertex = farther;
+        }
+        Some(vertex)
+    }
+
+    /// Smallest circle holding all the points, as its center and radius. It goes through the
+    /// vertices of a face, or has an edge as diameter.
+    pub fn smallest_enclosing_circle(&self) -> Option<(Point, f64)> {
+        let corners = self
+            .vertices()
+            .filter_map(|v| v.position())
+            .map(|p| p.to_f64())
+            .collect::<Vec<_>>();
+        let encloses = |center: &Point, radius: f64| {
+            corners
+                .iter()
+                .all(|p| p.distance(center) <= radius * (1.0 + 1e-12))
+        };
+
+        let circumcircles = self.faces().filter_map(|face| {
+            let center = face.circumcenter()?;
+            let radius = center.distance(&face.vertices().next()?.position()?.to_f64());
+            Some((center, radius))
+        });
+        let diameters = self
+            .edges()
+            .filter_map(|edge| {
+                let (v0, v1) = edge.vertices();
+                let (p0, p1) = (v0.position()?.to_f64(), v1.position()?.to_f64());
+                let center = Point {
+                    x: (p0.x + p1.x) / 2.0,
+                    y: (p0.y + p1.y) / 2.0,
+                };
+                Some((center, p0.distance(&p1) / 2.0))
+            })
+            .filter(|(center, radius)| encloses(center, *radius));
+
+        circumcircles
+            .chain(diameters)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Random;
+
+    fn random(seed: u64, count: usize) -> Box<[Point]> {
+        Random::new(seed).points(count).into()
+    }
+
+    #[test]
+    fn diagram() {
+        let points = random(0xd807_aa98_a303_0242, 200);
+        let farthest = Farthest::from(points.clone()).unwrap();
+        let corners = convex_hull(&points);
+
+        // The hull corners are the vertices, triangulated without any other point.
+        let mut vertices = farthest
+            .vertices()
+            .filter_map(|v| v.point_index())
+            .collect::<Vec<_>>();
+        vertices.sort();
+        let mut sorted = corners.clone();
+        sorted.sort();
+        assert_eq!(vertices, sorted);
+        let finite = farthest.faces().filter(|f| !f.is_infinite());
+        assert_eq!(finite.count(), corners.len() - 2);
+
+        // The circle through every face holds all the points.
+        for face in farthest.faces().filter(|f| !f.is_infinite()) {
+            let center = face.circumcenter().unwrap();
+            let radius = center.distance(&face.vertices().next().unwrap().position().unwrap());
+            assert!(
+                points
+                    .iter()
+                    .all(|p| p.distance(&center) <= radius * (1.0 + 1e-9))
+            );
+        }
+
+        let mut rng = Random::new(0x1234_5678);
+        for _ in 0..200 {
+            let query = Point {
+                x: 3.0 * rng.float() - 1.0,
+                y: 3.0 * rng.float() - 1.0,
+            };
+            let found = farthest.farthest(&query).unwrap().position().unwrap();
+            let brute = points
+                .iter()
+                .map(|p| p.distance(&query))
+                .fold(0.0, f64::max);
+            assert_eq!(found.distance(&query), brute);
+        }
+    }
+
+    #[test]
+    fn smallest_enclosing_circle() {
+        for seed in 0..5 {
+            let points = random(seed, 30);
+            let (center, radius) = Farthest::from(points.clone())
+                .unwrap()
+                .smallest_enclosing_circle()
+                .unwrap();
+            assert!(
+                points
+                    .iter()
+                    .all(|p| p.distance(&center) <= radius * (1.0 + 1e-9))
+            );
+
+            // Smallest of the circles through two or three of the points that hold them all.
+            let encloses = |center: &Point, radius: f64| {
+                points
+                    .iter()
+                    .all(|p| p.distance(center) <= radius * (1.0 + 1e-9))
+            };
+            let mut brute = f64::INFINITY;
+            for (i, a) in points.iter().enumerate() {
+                for (j, b) in points.iter().enumerate().skip(i + 1) {
+                    let middle = Point {
+                        x: (a.x + b.x) / 2.0,
+                        y: (a.y + b.y) / 2.0,
+                    };
+                    if encloses(&middle, a.distance(b) / 2.0) {
+                        brute = brute.min(a.distance(b) / 2.0);
+                    }
+                    for c in &points[j + 1..] {
+                        let center = Point::circumcenter(a, b, c);
+                        if encloses(&center, center.distance(a)) {
+                            brute = brute.min(center.distance(a));
+                        }
+                    }
+                }
+            }
+            assert!((radius - brute).abs() < 1e-9);
+        }
+
+        // An obtuse triangle has its longest side as diameter.
+        let points = [(0.0, 0.0), (4.0, 0.0), (2.0, 1.0)].map(|(x, y)| Point { x, y });
+        let (center, radius) = Farthest::from(Box::new(points))
+            .unwrap()
+            .smallest_enclosing_circle()
+            .unwrap();
+        assert!((center.x - 2.0).abs() < 1e-12 && center.y.abs() < 1e-12);
+        assert!((radius - 2.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,73 @@
+use super::scalar::Scalar;
+use crate::math;
+#[cfg(feature = "rand")]
+use rand::{Fill, Rng};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point<S = f64> {
+    pub x: S,
+    pub y: S,
+}
+
+impl<S: Scalar> Point<S> {
+    pub fn to_f64(&self) -> Point {
+        Point {
+            x: self.x.to_f64(),
+            y: self.y.to_f64(),
+        }
+    }
+
+    pub fn distance_squared(&self, other: &Point<S>) -> f64 {
+        let dx = self.x.to_f64() - other.x.to_f64();
+        let dy = self.y.to_f64() - other.y.to_f64();
+        dx * dx + dy * dy
+    }
+
+    pub fn distance(&self, other: &Point<S>) -> f64 {
+        math::sqrt(self.distance_squared(other))
+    }
+
+    pub fn is_ccw(p0: &Point<S>, p1: &Point<S>, p2: &Point<S>) -> bool {
+        S::is_ccw(p0, p1, p2)
+    }
+
+    fn circumdelta(p0: &Point<S>, p1: &Point<S>, p2: &Point<S>) -> (f64, f64) {
+        let (p0, p1, p2) = (p0.to_f64(), p1.to_f64(), p2.to_f64());
+        let dx = p1.x - p0.x;
+        let dy = p1.y - p0.y;
+        let ex = p2.x - p0.x;
+        let ey = p2.y - p0.y;
+
+        let bl = dx * dx + dy * dy;
+        let cl = ex * ex + ey * ey;
+        let d = 0.5 / (dx * ey - dy * ex);
+
+        ((ey * bl - dy * cl) * d, (dx * cl - ex * bl) * d)
+    }
+
+    pub fn square_circumradius(p0: &Point<S>, p1: &Point<S>, p2: &Point<S>) -> f64 {
+        let (dx, dy) = Self::circumdelta(p0, p1, p2);
+        dx * dx + dy * dy
+    }
+
+    pub fn circumcenter(p0: &Point<S>, p1: &Point<S>, p2: &Point<S>) -> Point {
+        let (dx, dy) = Self::circumdelta(p0, p1, p2);
+        Point {
+            x: p0.x.to_f64() + dx,
+            y: p0.y.to_f64() + dy,
+        }
+    }
+
+    pub fn in_circle(&self, p0: &Point<S>, p1: &Point<S>, p2: &Point<S>) -> bool {
+        S::in_circle(self, p0, p1, p2)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Fill for Point {
+    fn fill<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.x = rng.random();
+        self.y = rng.random();
+    }
+}
@@ -0,0 +1,369 @@
+use super::{Attribute, Attributes, Point, Scalar};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::{iter::successors, ptr};
+
+#[derive(Debug)]
+pub(crate) struct GraphEdge {
+    pub(crate) vertex: usize,
+    pub(crate) next: usize,
+    pub(crate) face: usize,
+}
+
+impl GraphEdge {
+    pub fn new(vertex: usize, next: usize, face: usize) -> GraphEdge {
+        GraphEdge { vertex, next, face }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct GraphFace {
+    pub(crate) edge: usize,
+}
+
+impl GraphFace {
+    pub fn new(edge: usize) -> GraphFace {
+        GraphFace { edge }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct GraphVertex {
+    pub(crate) position: Option<usize>,
+    pub(crate) edge: usize,
+}
+
+impl GraphVertex {
+    pub fn new(position: Option<usize>, edge: usize) -> GraphVertex {
+        GraphVertex { position, edge }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Edge<'a, S = f64> {
+    graph: &'a Graph<S>,
+    index: usize,
+}
+
+impl<S> PartialEq for Edge<'_, S> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.graph, other.graph) && self.index == other.index
+    }
+}
+
+impl<S> Eq for Edge<'_, S> {}
+
+impl<'a, S: Scalar> Edge<'a, S> {
+    pub fn id(&self) -> usize {
+        self.index / 2
+    }
+
+    pub fn vertices(&self) -> (Vertex<'a, S>, Vertex<'a, S>) {
+        let id = self.index;
+
+        let left = Vertex {
+            graph: self.graph,
+            index: self.graph.edges[id].vertex,
+        };
+
+        let right = Vertex {
+            graph: self.graph,
+            index: self.graph.edges[id ^ 1].vertex,
+        };
+
+        (left, right)
+    }
+
+    pub fn twin(&self) -> Edge<'a, S> {
+        self.graph.edge(self.index ^ 1)
+    }
+
+    pub fn next(&self) -> Edge<'a, S> {
+        self.graph.edge(self.graph.edges[self.index].next ^ 1)
+    }
+
+    pub fn face(&self) -> Face<'a, S> {
+        self.graph.face(self.graph.edges[self.index ^ 1].face)
+    }
+
+    pub fn attribute<T: Attribute>(&self, name: &str) -> Option<&'a T> {
+        self.graph.edge_attributes.get(name).map(|values| &values[self.id()])
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Face<'a, S = f64> {
+    graph: &'a Graph<S>,
+    index: usize,
+}
+
+impl<S> PartialEq for Face<'_, S> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.graph, other.graph) && self.index == other.index
+    }
+}
+
+impl<S> Eq for Face<'_, S> {}
+
+impl<'a, S: Scalar> Face<'a, S> {
+    pub fn id(&self) -> usize {
+        self.index
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = Edge<'a, S>> + use<'a, S> {
+        let graph = self.graph;
+        let start = graph.faces[self.index].edge;
+
+        successors(Some(graph.edge(start)), move |e| {
+            let next = graph.edges[e.index].next ^ 1;
+            if next == start {
+                None
+            } else {
+                Some(graph.edge(next))
+            }
+        })
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = Vertex<'a, S>> + use<'a, S> {
+        self.edges().map(|edge| edge.vertices().0)
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        self.vertices().any(|v| v.position().is_none())
+    }
+
+    pub fn attribute<T: Attribute>(&self, name: &str) -> Option<&'a T> {
+        self.graph.face_attributes.get(name).map(|values| &values[self.index])
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Vertex<'a, S = f64> {
+    graph: &'a Graph<S>,
+    index: usize,
+}
+
+impl<S> PartialEq for Vertex<'_, S> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.graph, other.graph) && self.index == other.index
+    }
+}
+
+impl<S> Eq for Vertex<'_, S> {}
+
+impl<'a, S: Scalar> Vertex<'a, S> {
+    pub fn id(&self) -> usize {
+        self.index
+    }
+
+    pub fn point_index(&self) -> Option<usize> {
+        self.graph.vertices[self.index].position
+    }
+
+    pub fn position(&self) -> Option<Point<S>> {
+        self.point_index().map(|i| self.graph.points[i])
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = Edge<'a, S>> + use<'a, S> {
+        let graph = self.graph;
+        let start = graph.vertices[self.index].edge;
+
+        successors(Some(start), move |&e| {
+            let next = graph.edges[e].next;
+            if next == start { None } else { Some(next) }
+        })
+        .map(move |e| graph.edge(e ^ 1))
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = Vertex<'a, S>> + use<'a, S> {
+        self.edges().map(|edge| edge.vertices().1)
+    }
+
+    pub fn faces(&self) -> impl Iterator<Item = Face<'a, S>> + use<'a, S> {
+        self.edges().map(|edge| edge.face())
+    }
+
+    pub fn is_on_hull(&self) -> bool {
+        self.neighbors().any(|v| v.position().is_none())
+    }
+
+    pub fn attribute<T: Attribute>(&self, name: &str) -> Option<&'a T> {
+        self.graph.vertex_attributes.get(name).map(|values| &values[self.index])
+    }
+}
+
+#[derive(Debug)]
+pub struct Graph<S = f64> {
+    points: Box<[Point<S>]>,
+    edges: Box<[GraphEdge]>,
+    faces: Box<[GraphFace]>,
+    vertices: Box<[GraphVertex]>,
+    edge_attributes: Attributes,
+    face_attributes: Attributes,
+    vertex_attributes: Attributes,
+}
+
+impl<S: Scalar> Graph<S> {
+    pub fn edge_count(&self) -> usize {
+        self.edges.len() / 2
+    }
+
+    pub fn face_count(&self) -> usize {
+        self.faces.len()
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn points(&self) -> &[Point<S>] {
+        &self.points
+    }
+
+    pub fn edge(&self, index: usize) -> Edge<'_, S> {
+        Edge { graph: self, index }
+    }
+
+    pub fn face(&self, index: usize) -> Face<'_, S> {
+        Face { graph: self, index }
+    }
+
+    pub fn vertex(&self, index: usize) -> Vertex<'_, S> {
+        Vertex { graph: self, index }
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = Edge<'_, S>> {
+        (0..self.edge_count()).map(move |i| self.edge(i * 2))
+    }
+
+    pub fn faces(&self) -> impl Iterator<Item = Face<'_, S>> {
+        (0..self.face_count()).map(move |i| self.face(i))
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = Vertex<'_, S>> {
+        (0..self.vertex_count()).map(move |i| self.vertex(i))
+    }
+
+    pub fn hull(&self) -> impl Iterator<Item = Vertex<'_, S>> {
+        self.vertex(0).neighbors()
+    }
+
+    pub fn edge_attributes(&self) -> &Attributes {
+        &self.edge_attributes
+    }
+
+    pub fn face_attributes(&self) -> &Attributes {
+        &self.face_attributes
+    }
+
+    pub fn vertex_attributes(&self) -> &Attributes {
+        &self.vertex_attributes
+    }
+
+    pub(crate) fn attributes_mut(&mut self) -> AttributesMut<'_, S> {
+        AttributesMut { graph: self }
+    }
+
+    // Takes the attributes of `previous`, a graph over the same points by index, for the
+    // vertices, edges and faces that have the same points here.
+    pub(crate) fn carry_attributes(&mut self, previous: &Graph<S>) {
+        let edge = |e: Edge<'_, S>| {
+            let (v0, v1) = e.vertices();
+            let (i0, i1) = (v0.point_index(), v1.point_index());
+            (e.id(), (i0.min(i1), i0.max(i1)))
+        };
+        let face = |f: Face<'_, S>| {
+            let mut key = [None; 3];
+            for (k, v) in key.iter_mut().zip(f.vertices()) {
+                *k = v.point_index();
+            }
+            key.sort_unstable();
+            (f.id(), key)
+        };
+        let vertex = |v: Vertex<'_, S>| (v.id(), v.point_index());
+
+        if !previous.edge_attributes.is_empty() {
+            let map = remap(previous.edges().map(edge), self.edges().map(edge));
+            self.edge_attributes = previous.edge_attributes.remap(&map);
+        }
+        if !previous.face_attributes.is_empty() {
+            let map = remap(previous.faces().map(face), self.faces().map(face));
+            self.face_attributes = previous.face_attributes.remap(&map);
+        }
+        if !previous.vertex_attributes.is_empty() {
+            let map = remap(previous.vertices().map(vertex), self.vertices().map(vertex));
+            self.vertex_attributes = previous.vertex_attributes.remap(&map);
+        }
+    }
+}
+
+// For every element of `current`, in id order, the id of the `previous` one with the same key.
+fn remap<K: Ord>(
+    previous: impl Iterator<Item = (usize, K)>,
+    current: impl Iterator<Item = (usize, K)>,
+) -> Vec<Option<usize>> {
+    let previous = previous.map(|(id, key)| (key, id)).collect::<BTreeMap<_, _>>();
+    current.map(|(_, key)| previous.get(&key).copied()).collect()
+}
+
+/// Mutable access to the attributes of a graph, leaving its topology as it is.
+pub struct AttributesMut<'a, S = f64> {
+    graph: &'a mut Graph<S>,
+}
+
+impl<S: Scalar> AttributesMut<'_, S> {
+    pub fn edges(&mut self) -> &mut Attributes {
+        &mut self.graph.edge_attributes
+    }
+
+    pub fn faces(&mut self) -> &mut Attributes {
+        &mut self.graph.face_attributes
+    }
+
+    pub fn vertices(&mut self) -> &mut Attributes {
+        &mut self.graph.vertex_attributes
+    }
+
+    pub fn set_edge<T, F>(&mut self, name: &str, f: F)
+    where
+        T: Attribute + Clone + Default,
+        F: FnMut(Edge<'_, S>) -> T,
+    {
+        let values = self.graph.edges().map(f).collect::<Vec<_>>();
+        self.graph.edge_attributes.insert(name, values);
+    }
+
+    pub fn set_face<T, F>(&mut self, name: &str, f: F)
+    where
+        T: Attribute + Clone + Default,
+        F: FnMut(Face<'_, S>) -> T,
+    {
+        let values = self.graph.faces().map(f).collect::<Vec<_>>();
+        self.graph.face_attributes.insert(name, values);
+    }
+
+    pub fn set_vertex<T, F>(&mut self, name: &str, f: F)
+    where
+        T: Attribute + Clone + Default,
+        F: FnMut(Vertex<'_, S>) -> T,
+    {
+        let values = self.graph.vertices().map(f).collect::<Vec<_>>();
+        self.graph.vertex_attributes.insert(name, values);
+    }
+}
+
+pub(crate) fn graph<S>(
+    points: Box<[Point<S>]>,
+    edges: Box<[GraphEdge]>,
+    faces: Box<[GraphFace]>,
+    vertices: Box<[GraphVertex]>,
+) -> Graph<S> {
+    Graph {
+        edge_attributes: Attributes::new(edges.len() / 2),
+        face_attributes: Attributes::new(faces.len()),
+        vertex_attributes: Attributes::new(vertices.len()),
+        points,
+        edges,
+        faces,
+        vertices,
+    }
+}
@@ -0,0 +1,85 @@
+use super::{
+    geometry::Point,
+    graph_datastructure::{Face, Graph, Vertex},
+    scalar::Scalar,
+};
+
+impl<S: Scalar> Graph<S> {
+    pub fn locate(&self, point: &Point<S>) -> Option<Face<'_, S>> {
+        self.faces()
+            .find(|f| !f.is_infinite())
+            .and_then(|start| self.locate_from(point, start))
+    }
+
+    pub fn locate_from<'a>(&'a self, point: &Point<S>, start: Face<'a, S>) -> Option<Face<'a, S>> {
+        self.walk(start, |p0, p1| Point::is_ccw(p1, p0, point))
+    }
+
+    // Same as `locate_from` for a point between the positions, like the samples of a raster
+    // over integer coordinates, tested in f64.
+    pub(crate) fn locate_f64_from<'a>(
+        &'a self,
+        point: &Point,
+        start: Face<'a, S>,
+    ) -> Option<Face<'a, S>> {
+        self.walk(start, |p0, p1| {
+            Point::is_ccw(&p1.to_f64(), &p0.to_f64(), point)
+        })
+    }
+
+    pub(crate) fn locate_f64(&self, point: &Point) -> Option<Face<'_, S>> {
+        self.faces()
+            .find(|f| !f.is_infinite())
+            .and_then(|start| self.locate_f64_from(point, start))
+    }
+
+    // Crosses the edges from `p0` to `p1` for which `beyond(p0, p1)` holds until none does.
+    fn walk<'a>(
+        &'a self,
+        start: Face<'a, S>,
+        beyond: impl Fn(&Point<S>, &Point<S>) -> bool,
+    ) -> Option<Face<'a, S>> {
+        let mut face = start;
+
+        'walk: loop {
+            if face.is_infinite() {
+                return None;
+            }
+
+            for edge in face.edges() {
+                let (v0, v1) = edge.vertices();
+                let (p0, p1) = (v0.position()?, v1.position()?);
+
+                if beyond(&p0, &p1) {
+                    face = edge.twin().face();
+                    continue 'walk;
+                }
+            }
+
+            return Some(face);
+        }
+    }
+
+    /// Vertex closest to `point`, found by walking to closer neighbors, which always reaches
+    /// it in a Delaunay triangulation.
+    pub fn nearest(&self, point: &Point<S>) -> Option<Vertex<'_, S>> {
+        let distance = |v: Vertex<'_, S>| v.position().map(|p| p.distance_squared(point));
+        let mut vertex = match self.locate(point) {
+            Some(face) => face.vertices().min_by(|a, b| {
+                let (a, b) = (distance(*a), distance(*b));
+                a.unwrap_or(f64::INFINITY)
+                    .total_cmp(&b.unwrap_or(f64::INFINITY))
+            })?,
+            None => self.vertices().find(|v| v.position().is_some())?,
+        };
+
+        while let Some(closer) = vertex
+            .neighbors()
+            .filter(|n| n.position().is_some())
+            .find(|&n| distance(n) < distance(vertex))
+        {
+            vertex = closer;
+        }
+        Some(vertex)
+    }
+}
@@ -0,0 +1,21 @@
+mod attributes;
+mod delaunay;
+mod farthest;
+mod geometry;
+mod graph_datastructure;
+mod locate;
+mod regular;
+mod scalar;
+mod voronoi;
+
+pub use attributes::{Attribute, Attributes};
+#[cfg(feature = "export")]
+pub(crate) use attributes::{write_csv_string, write_json_string};
+pub use delaunay::{Delaunay, Error};
+pub use farthest::Farthest;
+pub use geometry::Point;
+pub use graph_datastructure::{AttributesMut, Edge, Face, Graph, Vertex};
+pub use regular::{Regular, RegularError};
+pub use scalar::Scalar;
+pub(crate) use voronoi::clip_by_bisector;
+pub use voronoi::{CellMetrics, CellShape};
@@ -0,0 +1,339 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{fmt, ops::Deref};
+
+use super::{
+    delaunay::{Error, Triangulation, legalize, triangulate},
+    geometry::Point,
+    graph_datastructure::{AttributesMut, Face, Graph, Vertex, graph},
+    scalar::Scalar,
+    voronoi::clip_by_bisector,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegularError {
+    Triangulation(Error),
+    WeightCount,
+}
+
+impl fmt::Display for RegularError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegularError::Triangulation(error) => error.fmt(f),
+            RegularError::WeightCount => write!(f, "exactly one weight per point is required"),
+        }
+    }
+}
+
+impl core::error::Error for RegularError {}
+
+impl From<Error> for RegularError {
+    fn from(error: Error) -> Self {
+        RegularError::Triangulation(error)
+    }
+}
+
+/// Weighted Delaunay triangulation, the dual of the power diagram. Points whose weight is too
+/// small for them to own a power cell are redundant and left out of the triangulation.
+#[derive(Debug)]
+pub struct Regular<S = f64> {
+    graph: Graph<S>,
+    weights: Box<[f64]>,
+}
+
+impl<S> Deref for Regular<S> {
+    type Target = Graph<S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.graph
+    }
+}
+
+fn orientation(p0: &Point, p1: &Point, p2: &Point) -> f64 {
+    (p1.x - p0.x) * (p2.y - p0.y) - (p1.y - p0.y) * (p2.x - p0.x)
+}
+
+// Same determinant as `Scalar::in_circle`, with the points lifted to x² + y² - weight.
+fn in_power_circle(p: (Point, f64), p0: (Point, f64), p1: (Point, f64), p2: (Point, f64)) -> bool {
+    let lift = |(q, w): (Point, f64)| {
+        let (dx, dy) = (q.x - p.0.x, q.y - p.0.y);
+        (dx, dy, dx * dx + dy * dy - (w - p.1))
+    };
+    let ((dx, dy, ap), (ex, ey, bp), (fx, fy, cp)) = (lift(p0), lift(p1), lift(p2));
+
+    dx * (ey * cp - bp * fy) - dy * (ex * cp - bp * fx) + ap * (ex * fy - ey * fx) < 0.0
+}
+
+// Whether `p` is redundant against the triangle `p0`, `p1`, `p2` holding it: lifted to
+// x² + y² - weight, it is above the plane through the three others, which is above the lower
+// hull of the lifted points. `None` when the triangle doesn't hold `p`.
+fn redundant_in(
+    p: (Point, f64),
+    p0: (Point, f64),
+    p1: (Point, f64),
+    p2: (Point, f64),
+) -> Option<bool> {
+    let area = orientation(&p0.0, &p1.0, &p2.0);
+    if area == 0.0 {
+        return None;
+    }
+
+    let barycentric = [
+        orientation(&p.0, &p1.0, &p2.0) / area,
+        orientation(&p0.0, &p.0, &p2.0) / area,
+        orientation(&p0.0, &p1.0, &p.0) / area,
+    ];
+    if barycentric.iter().any(|&l| l < 0.0) {
+        return None;
+    }
+
+    let height = |(q, w): (Point, f64)| q.x * q.x + q.y * q.y - w;
+    let plane =
+        barycentric[0] * height(p0) + barycentric[1] * height(p1) + barycentric[2] * height(p2);
+    Some(height(p) > plane)
+}
+
+// Regular triangulation of all the points, or the points found to be redundant on the way.
+fn regular<S: Scalar>(
+    points: &[Point<S>],
+    weights: &[f64],
+) -> Result<Result<Triangulation, Vec<usize>>, Error> {
+    let lifted = |i: usize| (points[i].to_f64(), weights[i]);
+    let non_regular = |p0: usize, pa: usize, pb: usize, p1: usize| {
+        in_power_circle(lifted(p0), lifted(pa), lifted(pb), lifted(p1))
+    };
+    // Only convex quadrilaterals can be flipped, when `pa` and `pb` are on both sides of the
+    // diagonal from `p0` to `p1`.
+    let convex = |p0: usize, pa: usize, pb: usize, p1: usize| {
+        let (p0, p1) = (points[p0].to_f64(), points[p1].to_f64());
+        orientation(&p0, &p1, &points[pa].to_f64()) * orientation(&p0, &p1, &points[pb].to_f64())
+            < 0.0
+    };
+    let illegal = |p0: usize, pa: usize, pb: usize, p1: usize| {
+        convex(p0, pa, pb, p1) && non_regular(p0, pa, pb, p1)
+    };
+
+    let (mut edges, mut faces, mut vertices) = triangulate(points, &illegal)?;
+
+    // Points inserted later can leave edges that could not be flipped during the sweep, so
+    // flip until every edge is regular or blocked. A blocked edge has a vertex inside the
+    // triangle of the three others, which is tested for redundancy against it.
+    loop {
+        let mut flipped = false;
+        let mut redundant = Vec::new();
+
+        for t0e0 in 0..edges.len() {
+            let (va, vb) = (edges[t0e0].vertex, edges[t0e0 ^ 1].vertex);
+            let v0 = edges[edges[t0e0 ^ 1].next].vertex;
+            let v1 = edges[edges[t0e0].next].vertex;
+            if [va, vb, v0, v1].contains(&0) {
+                continue;
+            }
+
+            let [p0, pa, pb, p1] = [v0, va, vb, v1].map(|v| vertices[v].position.unwrap());
+            if !non_regular(p0, pa, pb, p1) {
+                continue;
+            }
+
+            if convex(p0, pa, pb, p1) {
+                legalize(&mut edges, &mut faces, &mut vertices, &illegal, t0e0);
+                flipped = true;
+            } else if let Some(p) = [(pa, pb), (pb, pa)].into_iter().find_map(|(p, other)| {
+                redundant_in(lifted(p), lifted(p0), lifted(other), lifted(p1))?.then_some(p)
+            }) {
+                redundant.push(p);
+            }
+        }
+
+        if !redundant.is_empty() {
+            return Ok(Err(redundant));
+        }
+        if !flipped {
+            return Ok(Ok((edges, faces, vertices)));
+        }
+    }
+}
+
+impl<S: Scalar> Regular<S> {
+    pub fn from(points: Box<[Point<S>]>, weights: Box<[f64]>) -> Result<Self, RegularError> {
+        if points.len() != weights.len() {
+            return Err(RegularError::WeightCount);
+        }
+
+        // Triangulate the points not known to be redundant until none is found. Redundant
+        // points stay redundant among any superset, so every round only removes points.
+        let mut active = (0..points.len()).collect::<Vec<_>>();
+        loop {
+            let subset = active.iter().map(|&i| points[i]).collect::<Vec<_>>();
+            let subset_weights = active.iter().map(|&i| weights[i]).collect::<Vec<_>>();
+
+            match regular(&subset, &subset_weights)? {
+                Ok((edges, faces, mut vertices)) => {
+                    for vertex in vertices.iter_mut() {
+                        vertex.position = vertex.position.map(|i| active[i]);
+                    }
+
+                    return Ok(Regular {
+                        graph: graph(
+                            points,
+                            edges.into_boxed_slice(),
+                            faces.into_boxed_slice(),
+                            vertices.into_boxed_slice(),
+                        ),
+                        weights,
+                    });
+                }
+                Err(redundant) => {
+                    let mut keep = vec![true; active.len()];
+                    for i in redundant {
+                        keep[i] = false;
+                    }
+                    let mut keep = keep.into_iter();
+                    active.retain(|_| keep.next().unwrap());
+                }
+            }
+        }
+    }
+
+    pub fn attributes_mut(&mut self) -> AttributesMut<'_, S> {
+        self.graph.attributes_mut()
+    }
+
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Indices of the input points left out of the triangulation.
+    pub fn redundant(&self) -> impl Iterator<Item = usize> + '_ {
+        let mut used = vec![false; self.points().len()];
+        for vertex in self.vertices() {
+            if let Some(i) = vertex.point_index() {
+                used[i] = true;
+            }
+        }
+        used.into_iter()
+            .enumerate()
+            .filter_map(|(i, used)| (!used).then_some(i))
+    }
+
+    fn weighted(&self, vertex: Vertex<'_, S>) -> Option<(Point, f64)> {
+        Some((
+            vertex.position()?.to_f64(),
+            self.weights[vertex.point_index()?],
+        ))
+    }
+
+    /// The point with equal power distance to the three vertices of `face`.
+    pub fn power_center(&self, face: Face<'_, S>) -> Option<Point> {
+        let mut vertices = face.vertices();
+        let (p0, w0) = self.weighted(vertices.next()?)?;
+        let (p1, w1) = self.weighted(vertices.next()?)?;
+        let (p2, w2) = self.weighted(vertices.next()?)?;
+
+        let (dx, dy) = (p1.x - p0.x, p1.y - p0.y);
+        let (ex, ey) = (p2.x - p0.x, p2.y - p0.y);
+        let bl = dx * dx + dy * dy - (w1 - w0);
+        let cl = ex * ex + ey * ey - (w2 - w0);
+        let d = 0.5 / (dx * ey - dy * ex);
+
+        Some(Point {
+            x: p0.x + (ey * bl - dy * cl) * d,
+            y: p0.y + (dx * cl - ex * bl) * d,
+        })
+    }
+
+    /// Power centers around `vertex`, open for vertices on the hull like `Vertex::voronoi_cell`.
+    pub fn power_cell(&self, vertex: Vertex<'_, S>) -> Vec<Point> {
+        let faces = vertex.faces().collect::<Vec<_>>();
+        let start = faces
+            .iter()
+            .rposition(|f| f.is_infinite())
+            .map_or(0, |i| i + 1);
+
+        faces[start..]
+            .iter()
+            .chain(&faces[..start])
+            .filter_map(|&f| self.power_center(f))
+            .collect()
+    }
+
+    /// Power cell of `vertex` clipped to the convex polygon `clip`.
+    pub fn clipped_power_cell(&self, vertex: Vertex<'_, S>, clip: &[Point]) -> Vec<Point> {
+        match self.weighted(vertex) {
+            Some((site, weight)) => vertex.neighbors().filter_map(|v| self.weighted(v)).fold(
+                clip.to_vec(),
+                |polygon, (other, other_weight)| {
+                    clip_by_bisector(&polygon, &site, &other, weight - other_weight)
+                },
+            ),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Random;
+
+    fn triangle_with(point: Point, weight: f64) -> Regular {
+        let points = [
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 4.0, y: 0.0 },
+            Point { x: 0.0, y: 4.0 },
+            point,
+        ];
+        Regular::from(points.into(), [0.0, 0.0, 0.0, weight].into()).unwrap()
+    }
+
+    #[test]
+    fn redundant_below_the_plane_of_the_triangle() {
+        // Lifted, the corners are at 0, 16 and 16, whose plane is at 8 above (1, 1), where
+        // the point is at 2 - weight.
+        let inside = Point { x: 1.0, y: 1.0 };
+        for (weight, redundant) in [(5.0, false), (0.0, false), (-5.0, false), (-7.0, true)] {
+            let regular = triangle_with(inside, weight);
+            let expected: &[usize] = if redundant { &[3] } else { &[] };
+            assert_eq!(
+                regular.redundant().collect::<Vec<_>>(),
+                expected,
+                "weight {weight}"
+            );
+        }
+    }
+
+    #[test]
+    fn weight_count() {
+        let points = [Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 0.0 }];
+        let error = Regular::from(points.into(), [0.0].into()).unwrap_err();
+        assert_eq!(error, RegularError::WeightCount);
+    }
+
+    #[test]
+    fn redundant_points_own_no_power_cell() {
+        let mut random = Random::new(0x2545_f491_4f6c_dd1d);
+        let points = random.points(200);
+        let weights = (0..200).map(|_| 0.01 * random.float()).collect::<Vec<_>>();
+        let regular = Regular::from(points.clone().into(), weights.clone().into()).unwrap();
+        let redundant = regular.redundant().collect::<Vec<_>>();
+        assert!(!redundant.is_empty());
+
+        let clip =
+            [(-1e3, -1e3), (1e3, -1e3), (1e3, 1e3), (-1e3, 1e3)].map(|(x, y)| Point { x, y });
+        for (i, point) in points.iter().enumerate() {
+            let cell = points.iter().enumerate().filter(|&(j, _)| j != i).fold(
+                clip.to_vec(),
+                |polygon, (j, other)| {
+                    clip_by_bisector(&polygon, point, other, weights[i] - weights[j])
+                },
+            );
+            let area = (0..cell.len())
+                .map(|k| {
+                    let (p0, p1) = (&cell[k], &cell[(k + 1) % cell.len()]);
+                    p0.x * p1.y - p1.x * p0.y
+                })
+                .sum::<f64>()
+                .abs();
+            assert_eq!(redundant.contains(&i), area < 1e-12, "point {i}");
+        }
+    }
+}
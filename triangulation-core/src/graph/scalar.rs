@@ -0,0 +1,102 @@
+use super::geometry::Point;
+use core::fmt::{Debug, Display};
+
+pub trait Scalar: Copy + PartialOrd + Debug + Display {
+    fn to_f64(self) -> f64;
+
+    fn is_ccw(p0: &Point<Self>, p1: &Point<Self>, p2: &Point<Self>) -> bool {
+        let (p0, p1, p2) = (p0.to_f64(), p1.to_f64(), p2.to_f64());
+        (p1.y - p0.y) * (p2.x - p1.x) - (p1.x - p0.x) * (p2.y - p1.y) > 0.0
+    }
+
+    fn in_circle(p: &Point<Self>, p0: &Point<Self>, p1: &Point<Self>, p2: &Point<Self>) -> bool {
+        let (p, p0, p1, p2) = (p.to_f64(), p0.to_f64(), p1.to_f64(), p2.to_f64());
+
+        let dx = p0.x - p.x;
+        let dy = p0.y - p.y;
+        let ex = p1.x - p.x;
+        let ey = p1.y - p.y;
+        let fx = p2.x - p.x;
+        let fy = p2.y - p.y;
+
+        let ap = dx * dx + dy * dy;
+        let bp = ex * ex + ey * ey;
+        let cp = fx * fx + fy * fy;
+
+        dx * (ey * cp - bp * fy) - dy * (ex * cp - bp * fx) + ap * (ex * fy - ey * fx) < 0.0
+    }
+}
+
+impl Scalar for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl Scalar for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+const LOW_BITS: u32 = 34;
+const LOW_MASK: i128 = (1 << LOW_BITS) - 1;
+
+// Sign of a0 * b0 + a1 * b1 + a2 * b2 for factors below 2^96 in magnitude, computed
+// without overflow by splitting every factor into high and low limbs.
+fn sum_of_products_is_negative(terms: [(i128, i128); 3]) -> bool {
+    let (high, middle, low) = terms.iter().fold((0, 0, 0), |(high, middle, low), &(a, b)| {
+        let (a_high, a_low) = (a >> LOW_BITS, a & LOW_MASK);
+        let (b_high, b_low) = (b >> LOW_BITS, b & LOW_MASK);
+        (
+            high + a_high * b_high,
+            middle + a_high * b_low + a_low * b_high,
+            low + a_low * b_low,
+        )
+    });
+
+    let middle = middle + (low >> LOW_BITS);
+    let high = high + (middle >> LOW_BITS);
+    high < 0
+}
+
+macro_rules! exact_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn is_ccw(p0: &Point<Self>, p1: &Point<Self>, p2: &Point<Self>) -> bool {
+                    let (x0, y0) = (p0.x as i128, p0.y as i128);
+                    let (x1, y1) = (p1.x as i128, p1.y as i128);
+                    let (x2, y2) = (p2.x as i128, p2.y as i128);
+
+                    (y1 - y0) * (x2 - x1) - (x1 - x0) * (y2 - y1) > 0
+                }
+
+                fn in_circle(p: &Point<Self>, p0: &Point<Self>, p1: &Point<Self>, p2: &Point<Self>) -> bool {
+                    let dx = p0.x as i128 - p.x as i128;
+                    let dy = p0.y as i128 - p.y as i128;
+                    let ex = p1.x as i128 - p.x as i128;
+                    let ey = p1.y as i128 - p.y as i128;
+                    let fx = p2.x as i128 - p.x as i128;
+                    let fy = p2.y as i128 - p.y as i128;
+
+                    let ap = dx * dx + dy * dy;
+                    let bp = ex * ex + ey * ey;
+                    let cp = fx * fx + fy * fy;
+
+                    sum_of_products_is_negative([
+                        (ap, ex * fy - ey * fx),
+                        (bp, fx * dy - fy * dx),
+                        (cp, dx * ey - dy * ex),
+                    ])
+                }
+            }
+        )*
+    };
+}
+
+exact_scalar!(i16, i32);
@@ -0,0 +1,289 @@
+use super::{
+    geometry::Point,
+    graph_datastructure::{Face, Graph, Vertex},
+    scalar::Scalar,
+};
+use alloc::{vec, vec::Vec};
+
+#[derive(Debug, Clone, Copy)]
+pub struct CellShape {
+    pub area: f64,
+    pub centroid: Point,
+    pub perimeter: f64,
+    /// Integrals of (x - cx)², (y - cy)² and (x - cx)(y - cy) over the cell, with `centroid`
+    /// as (cx, cy).
+    pub moments: [f64; 3],
+}
+
+/// Voronoi cell of a vertex, with a shape only for bounded cells or, when clipped, for cells
+/// reaching into the clip polygon.
+#[derive(Debug, Clone, Default)]
+pub struct CellMetrics {
+    pub neighbors: Vec<usize>,
+    pub shape: Option<CellShape>,
+}
+
+// Integrals over the cell relative to its site, summed one Voronoi edge at a time.
+#[derive(Clone, Copy, Default)]
+struct Sums {
+    bounded: bool,
+    area: f64,
+    x: f64,
+    y: f64,
+    xx: f64,
+    yy: f64,
+    xy: f64,
+    perimeter: f64,
+}
+
+impl Sums {
+    fn add(&mut self, site: &Point, p0: &Point, p1: &Point) {
+        let (x0, y0, x1, y1) = (p0.x - site.x, p0.y - site.y, p1.x - site.x, p1.y - site.y);
+        let cross = x0 * y1 - x1 * y0;
+        self.area += cross;
+        self.x += (x0 + x1) * cross;
+        self.y += (y0 + y1) * cross;
+        self.xx += (x0 * x0 + x0 * x1 + x1 * x1) * cross;
+        self.yy += (y0 * y0 + y0 * y1 + y1 * y1) * cross;
+        self.xy += (x0 * y1 + 2.0 * x0 * y0 + 2.0 * x1 * y1 + x1 * y0) * cross;
+        self.perimeter += p0.distance(p1);
+    }
+
+    fn shape(&self, site: &Point) -> Option<CellShape> {
+        if !self.bounded || self.area == 0.0 {
+            return None;
+        }
+
+        // Every cell is walked the same way around, which may be clockwise.
+        let sign = self.area.signum();
+        let area = sign * self.area / 2.0;
+        let (cx, cy) = (self.x / (3.0 * self.area), self.y / (3.0 * self.area));
+        let (xx, yy, xy) = (
+            sign * self.xx / 12.0 - area * cx * cx,
+            sign * self.yy / 12.0 - area * cy * cy,
+            sign * self.xy / 24.0 - area * cx * cy,
+        );
+
+        Some(CellShape {
+            area,
+            centroid: Point {
+                x: site.x + cx,
+                y: site.y + cy,
+            },
+            perimeter: self.perimeter,
+            moments: [xx, yy, xy],
+        })
+    }
+}
+
+// Keeps the part of `polygon` closer to `site` than to `other`, in power distance when the
+// weights of the two differ by `offset`.
+pub(crate) fn clip_by_bisector(
+    polygon: &[Point],
+    site: &Point,
+    other: &Point,
+    offset: f64,
+) -> Vec<Point> {
+    let side = |p: &Point| p.distance_squared(site) - p.distance_squared(other) - offset;
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+
+    for (i, current) in polygon.iter().enumerate() {
+        let next = &polygon[(i + 1) % polygon.len()];
+        let (d0, d1) = (side(current), side(next));
+
+        if d0 <= 0.0 {
+            clipped.push(*current);
+        }
+
+        if (d0 < 0.0 && d1 > 0.0) || (d0 > 0.0 && d1 < 0.0) {
+            let t = d0 / (d0 - d1);
+            clipped.push(Point {
+                x: current.x + t * (next.x - current.x),
+                y: current.y + t * (next.y - current.y),
+            });
+        }
+    }
+
+    clipped
+}
+
+impl<S: Scalar> Face<'_, S> {
+    pub fn circumcenter(&self) -> Option<Point> {
+        let mut positions = self.vertices().map(|v| v.position());
+
+        match (positions.next()?, positions.next()?, positions.next()?) {
+            (Some(p0), Some(p1), Some(p2)) => Some(Point::circumcenter(&p0, &p1, &p2)),
+            _ => None,
+        }
+    }
+}
+
+impl<S: Scalar> Vertex<'_, S> {
+    pub fn voronoi_cell(&self) -> Vec<Point> {
+        let faces = self.faces().collect::<Vec<_>>();
+        let start = faces
+            .iter()
+            .rposition(|f| f.is_infinite())
+            .map_or(0, |i| i + 1);
+
+        faces[start..]
+            .iter()
+            .chain(&faces[..start])
+            .filter_map(|f| f.circumcenter())
+            .collect()
+    }
+
+    pub fn clipped_voronoi_cell(&self, clip: &[Point]) -> Vec<Point> {
+        match self.position() {
+            Some(site) => self
+                .neighbors()
+                .filter_map(|v| v.position())
+                .map(|p| p.to_f64())
+                .fold(clip.to_vec(), |polygon, other| {
+                    clip_by_bisector(&polygon, &site.to_f64(), &other, 0.0)
+                }),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl<S: Scalar> Graph<S> {
+    /// Metrics of the Voronoi cell of every vertex, by vertex id, from a single pass over the
+    /// edges: each Delaunay edge is the Voronoi edge between the cells of its two vertices.
+    /// The cells of the hull vertices are unbounded and have no shape, `clipped_voronoi_metrics`
+    /// gives them one.
+    pub fn voronoi_metrics(&self) -> Vec<CellMetrics> {
+        let centers = self.faces().map(|f| f.circumcenter()).collect::<Vec<_>>();
+        let mut metrics = vec![CellMetrics::default(); self.vertex_count()];
+        let mut sums = vec![
+            Sums {
+                bounded: true,
+                ..Sums::default()
+            };
+            self.vertex_count()
+        ];
+
+        for edge in self.edges() {
+            let (v0, v1) = edge.vertices();
+            let (Some(p0), Some(p1)) = (v0.position(), v1.position()) else {
+                continue;
+            };
+            metrics[v0.id()].neighbors.push(v1.id());
+            metrics[v1.id()].neighbors.push(v0.id());
+
+            match (centers[edge.face().id()], centers[edge.twin().face().id()]) {
+                (Some(c0), Some(c1)) => {
+                    sums[v0.id()].add(&p0.to_f64(), &c0, &c1);
+                    sums[v1.id()].add(&p1.to_f64(), &c1, &c0);
+                }
+                _ => {
+                    sums[v0.id()].bounded = false;
+                    sums[v1.id()].bounded = false;
+                }
+            }
+        }
+
+        for (vertex, metrics) in self.vertices().zip(&mut metrics) {
+            metrics.shape = vertex
+                .position()
+                .and_then(|site| sums[vertex.id()].shape(&site.to_f64()));
+        }
+        metrics
+    }
+    /// Metrics of the Voronoi cells clipped to the convex polygon `clip`, so that the cells of
+    /// the hull have a shape too. The perimeter includes the sides along `clip`.
+    pub fn clipped_voronoi_metrics(&self, clip: &[Point]) -> Vec<CellMetrics> {
+        let mut metrics = self.voronoi_metrics();
+
+        for (vertex, metrics) in self.vertices().zip(&mut metrics) {
+            let cell = vertex.clipped_voronoi_cell(clip);
+            metrics.shape = vertex.position().and_then(|site| {
+                let mut sums = Sums {
+                    bounded: true,
+                    ..Sums::default()
+                };
+                for (i, p) in cell.iter().enumerate() {
+                    sums.add(&site.to_f64(), p, &cell[(i + 1) % cell.len()]);
+                }
+                sums.shape(&site.to_f64())
+            });
+        }
+        metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Delaunay, Point, math, testing::Random};
+    use alloc::vec::Vec;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn triangular_grid() {
+        // Rows of points one apart, every other one shifted by half, whose interior cells are
+        // regular hexagons of side 1 / sqrt(3).
+        let height = math::sqrt(3.0) / 2.0;
+        let points = (0..10)
+            .flat_map(|row| {
+                (0..10).map(move |column| Point {
+                    x: column as f64 + 0.5 * (row % 2) as f64,
+                    y: row as f64 * height,
+                })
+            })
+            .collect::<Vec<_>>();
+        let delaunay = Delaunay::from(points.into()).unwrap();
+
+        let side = 1.0 / math::sqrt(3.0);
+        let moment = 5.0 * math::sqrt(3.0) / 16.0 * side * side * side * side;
+        let mut interior = 0;
+        for (vertex, metrics) in delaunay.vertices().zip(delaunay.voronoi_metrics()) {
+            if vertex.is_on_hull() {
+                assert!(metrics.shape.is_none());
+            }
+            // Away from the rows and columns of the border.
+            let Some(index) = vertex.point_index() else {
+                continue;
+            };
+            if !(1..9).contains(&(index / 10)) || !(1..9).contains(&(index % 10)) {
+                continue;
+            }
+            interior += 1;
+            let site = vertex.position().unwrap();
+            let shape = metrics.shape.unwrap();
+            assert_eq!(metrics.neighbors.len(), 6);
+            assert!(close(shape.area, height));
+            assert!(close(shape.perimeter, 6.0 * side));
+            assert!(close(shape.centroid.x, site.x) && close(shape.centroid.y, site.y));
+            assert!(close(shape.moments[0], moment) && close(shape.moments[1], moment));
+            assert!(close(shape.moments[2], 0.0));
+        }
+        assert_eq!(interior, 64);
+    }
+
+    #[test]
+    fn clipped() {
+        let points = Random::new(0x243f_6a88_85a3_08d3).points(200);
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(x, y)| Point { x, y });
+
+        let metrics = delaunay.clipped_voronoi_metrics(&square);
+        let area = metrics
+            .iter()
+            .map(|metrics| metrics.shape.map_or(0.0, |shape| shape.area))
+            .sum::<f64>();
+        assert!(close(area, 1.0));
+        for (vertex, metrics) in delaunay.vertices().zip(&metrics) {
+            assert_eq!(metrics.shape.is_some(), vertex.position().is_some());
+        }
+
+        // Clipping only ever shrinks the bounded cells.
+        for (clipped, metrics) in metrics.iter().zip(delaunay.voronoi_metrics()) {
+            if let (Some(clipped), Some(shape)) = (clipped.shape, metrics.shape) {
+                assert!(clipped.area <= shape.area + 1e-12);
+            }
+        }
+    }
+}
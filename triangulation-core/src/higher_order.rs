@@ -0,0 +1,235 @@
+use crate::{Graph, Point, Scalar, graph::clip_by_bisector, polygon};
+use alloc::{collections::BTreeSet, collections::VecDeque, vec, vec::Vec};
+
+/// Cell of the order-k Voronoi diagram, where the k nearest points are `sites`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Cell {
+    /// Point indices, in increasing order.
+    pub sites: Vec<usize>,
+    pub polygon: Vec<Point>,
+}
+
+// Finite vertices next to the sites. The k nearest vertices of a point are connected in the
+// triangulation, so that the next nearest one is among them.
+fn neighbors<S: Scalar>(graph: &Graph<S>, sites: &[usize]) -> BTreeSet<usize> {
+    sites
+        .iter()
+        .flat_map(|&i| graph.vertex(i).neighbors())
+        .filter(|v| v.position().is_some() && !sites.contains(&v.id()))
+        .map(|v| v.id())
+        .collect()
+}
+
+// Part of `clip` closer to every site than to the vertices next to them, the only ones that
+// can bound the cell.
+fn clip_cell<S: Scalar>(graph: &Graph<S>, sites: &[usize], clip: &[Point]) -> Vec<Point> {
+    let position = |i: usize| graph.vertex(i).position().unwrap().to_f64();
+    let others = neighbors(graph, sites)
+        .into_iter()
+        .map(position)
+        .collect::<Vec<_>>();
+
+    let mut polygon = clip.to_vec();
+    for site in sites.iter().map(|&i| position(i)) {
+        for other in &others {
+            polygon = clip_by_bisector(&polygon, &site, other, 0.0);
+        }
+    }
+    polygon
+}
+
+// Ids of the `k` vertices nearest to `point`: the nearest one, walking to closer neighbors
+// from the face holding the point, then every next one among the vertices next to those.
+fn nearest<S: Scalar>(graph: &Graph<S>, point: &Point, k: usize) -> Vec<usize> {
+    let distance = |i: usize| {
+        graph
+            .vertex(i)
+            .position()
+            .map_or(f64::INFINITY, |p| p.to_f64().distance_squared(point))
+    };
+    let closest = |a: &usize, b: &usize| distance(*a).total_cmp(&distance(*b));
+
+    let start = match graph.locate_f64(point) {
+        Some(face) => face.vertices().map(|v| v.id()).min_by(closest),
+        None => graph
+            .vertices()
+            .find(|v| v.position().is_some())
+            .map(|v| v.id()),
+    };
+    let Some(mut first) = start else {
+        return Vec::new();
+    };
+    while let Some(closer) = graph
+        .vertex(first)
+        .neighbors()
+        .map(|v| v.id())
+        .find(|&i| distance(i) < distance(first))
+    {
+        first = closer;
+    }
+
+    let mut sites = vec![first];
+    while sites.len() < k {
+        let Some(next) = neighbors(graph, &sites).into_iter().min_by(closest) else {
+            break;
+        };
+        sites.push(next);
+    }
+    sites.sort_unstable();
+    sites
+}
+
+/// Order-k Voronoi diagram inside the convex polygon `clip`, for small `k`. Cells are found
+/// one from the other: across an edge of a cell, one of its sites is traded for a vertex next
+/// to them.
+pub fn voronoi<S: Scalar>(graph: &Graph<S>, k: usize, clip: &[Point]) -> Vec<Cell> {
+    let count = graph.vertices().filter(|v| v.position().is_some()).count();
+    if k == 0 || k > count || clip.len() < 3 {
+        return Vec::new();
+    }
+
+    // Starting from the center and the corners, in case ties leave some of them on an edge.
+    let center = Point {
+        x: clip.iter().map(|p| p.x).sum::<f64>() / clip.len() as f64,
+        y: clip.iter().map(|p| p.y).sum::<f64>() / clip.len() as f64,
+    };
+    let mut seen = BTreeSet::new();
+    let mut queue = core::iter::once(&center)
+        .chain(clip)
+        .map(|p| nearest(graph, p, k))
+        .filter(|sites| seen.insert(sites.clone()))
+        .collect::<VecDeque<_>>();
+    let size = clip
+        .iter()
+        .flat_map(|p| clip.iter().map(move |q| p.distance(q)))
+        .fold(0.0, f64::max);
+
+    let mut cells = Vec::new();
+    while let Some(sites) = queue.pop_front() {
+        let polygon = clip_cell(graph, &sites, clip);
+        if polygon.len() < 3 || polygon::signed_area(&polygon).abs() <= 1e-24 * size * size {
+            continue;
+        }
+
+        // In the middle of an edge between two cells, the farthest site of this one is as far
+        // as the nearest other vertex. Edges on the border of `clip` are closer to the sites.
+        for i in 0..polygon.len() {
+            let (p0, p1) = (&polygon[i], &polygon[(i + 1) % polygon.len()]);
+            if p0.distance(p1) <= 1e-12 * size {
+                continue;
+            }
+            let middle = Point {
+                x: (p0.x + p1.x) / 2.0,
+                y: (p0.y + p1.y) / 2.0,
+            };
+
+            let distance = |i: usize| {
+                let site = graph.vertex(i).position().unwrap();
+                (site.to_f64().distance(&middle), i)
+            };
+            let (d0, site) = sites
+                .iter()
+                .map(|&i| distance(i))
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+                .unwrap();
+            let Some((d1, other)) = neighbors(graph, &sites)
+                .into_iter()
+                .map(distance)
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+            else {
+                continue;
+            };
+            if d1 - d0 > 1e-9 * size {
+                continue;
+            }
+
+            let mut next = sites.clone();
+            next.retain(|&i| i != site);
+            next.push(other);
+            next.sort_unstable();
+            if seen.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+
+        let mut sites = sites
+            .iter()
+            .filter_map(|&i| graph.vertex(i).point_index())
+            .collect::<Vec<_>>();
+        sites.sort_unstable();
+        cells.push(Cell { sites, polygon });
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    fn square() -> [Point; 4] {
+        [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(x, y)| Point { x, y })
+    }
+
+    fn centroid(polygon: &[Point]) -> Point {
+        let (mut area, mut x, mut y) = (0.0, 0.0, 0.0);
+        for (i, p0) in polygon.iter().enumerate() {
+            let p1 = &polygon[(i + 1) % polygon.len()];
+            let cross = p0.x * p1.y - p1.x * p0.y;
+            area += cross;
+            x += (p0.x + p1.x) * cross;
+            y += (p0.y + p1.y) * cross;
+        }
+        Point {
+            x: x / (3.0 * area),
+            y: y / (3.0 * area),
+        }
+    }
+
+    #[test]
+    fn first_order() {
+        let points = Random::new(0x9bdc_06a7_25c7_1235).points(100);
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        let cells = voronoi(&delaunay, 1, &square());
+
+        assert_eq!(cells.len(), 100);
+        for vertex in delaunay.vertices() {
+            let Some(index) = vertex.point_index() else {
+                continue;
+            };
+            let cell = cells.iter().find(|cell| cell.sites == [index]).unwrap();
+            let voronoi = vertex.clipped_voronoi_cell(&square());
+            let area = polygon::signed_area(&cell.polygon).abs();
+            assert!((area - polygon::signed_area(&voronoi).abs()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn higher_orders() {
+        let points = Random::new(0x2de9_2c6f_592b_0275).points(60);
+        // Labels are point indices, which differ from vertex ids.
+        let delaunay = Delaunay::from(points.clone().into()).unwrap();
+
+        for k in 1..=4 {
+            let cells = voronoi(&delaunay, k, &square());
+            let area = cells
+                .iter()
+                .map(|cell| polygon::signed_area(&cell.polygon).abs())
+                .sum::<f64>();
+            assert!((area - 1.0).abs() < 1e-9);
+
+            for cell in &cells {
+                let center = centroid(&cell.polygon);
+                let mut nearest = (0..points.len()).collect::<Vec<_>>();
+                nearest.sort_by(|&a, &b| {
+                    let (a, b) = (&points[a], &points[b]);
+                    a.distance(&center).total_cmp(&b.distance(&center))
+                });
+                nearest.truncate(k);
+                nearest.sort_unstable();
+                assert_eq!(cell.sites, nearest);
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use super::InterpolationError;
+use crate::{Face, Graph, Point, Scalar};
+use alloc::vec::Vec;
+
+/// Barycentric interpolation in the triangles, at f64 points whatever the coordinates of the
+/// graph.
+pub struct Linear<'a, S = f64> {
+    graph: &'a Graph<S>,
+    values: &'a [f64],
+}
+
+fn signed_area(p0: &Point, p1: &Point, p2: &Point) -> f64 {
+    (p1.x - p0.x) * (p2.y - p0.y) - (p2.x - p0.x) * (p1.y - p0.y)
+}
+
+impl<'a, S: Scalar> Linear<'a, S> {
+    pub fn new(graph: &'a Graph<S>, values: &'a [f64]) -> Result<Self, InterpolationError> {
+        if values.len() != graph.points().len() {
+            return Err(InterpolationError::ValueCount);
+        }
+        Ok(Linear { graph, values })
+    }
+
+    fn interpolate_in(&self, face: Face<'a, S>, point: &Point) -> Option<f64> {
+        let mut vertices = face.vertices();
+        let (v0, v1, v2) = (vertices.next()?, vertices.next()?, vertices.next()?);
+        let (p0, p1, p2) = (
+            v0.position()?.to_f64(),
+            v1.position()?.to_f64(),
+            v2.position()?.to_f64(),
+        );
+
+        let area = signed_area(&p0, &p1, &p2);
+        let w0 = signed_area(point, &p1, &p2) / area;
+        let w1 = signed_area(&p0, point, &p2) / area;
+        let w2 = 1.0 - w0 - w1;
+
+        Some(
+            w0 * self.values[v0.point_index()?]
+                + w1 * self.values[v1.point_index()?]
+                + w2 * self.values[v2.point_index()?],
+        )
+    }
+
+    pub fn interpolate(&self, point: &Point) -> Option<f64> {
+        self.graph
+            .locate_f64(point)
+            .and_then(|face| self.interpolate_in(face, point))
+    }
+
+    pub fn raster(
+        &self,
+        min: &Point,
+        max: &Point,
+        width: usize,
+        height: usize,
+    ) -> Vec<Option<f64>> {
+        let step = |min: f64, max: f64, count: usize| {
+            if count > 1 {
+                (max - min) / (count - 1) as f64
+            } else {
+                0.0
+            }
+        };
+        let (dx, dy) = (step(min.x, max.x, width), step(min.y, max.y, height));
+
+        let mut samples = Vec::with_capacity(width * height);
+        let mut hint = self.graph.faces().find(|f| !f.is_infinite());
+
+        for j in 0..height {
+            let y = min.y + j as f64 * dy;
+            let row = samples.len();
+
+            for k in 0..width {
+                // Serpentine order so that consecutive samples are always neighbors.
+                let i = if j % 2 == 0 { k } else { width - 1 - k };
+                let point = Point {
+                    x: min.x + i as f64 * dx,
+                    y,
+                };
+
+                let face = hint.and_then(|start| self.graph.locate_f64_from(&point, start));
+                if face.is_some() {
+                    hint = face;
+                }
+                samples.push(face.and_then(|face| self.interpolate_in(face, &point)));
+            }
+
+            if j % 2 == 1 {
+                samples[row..].reverse();
+            }
+        }
+
+        samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    #[test]
+    fn linear_fields_are_exact() {
+        let mut random = Random::new(0x0123_4567_89ab_cdef);
+        let points = random.points(100);
+        let field = |p: &Point| 3.0 * p.x - 2.0 * p.y + 0.5;
+        let values = points.iter().map(field).collect::<Vec<_>>();
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        let linear = Linear::new(&delaunay, &values).unwrap();
+
+        // Inside the hull of random points in the unit square, away from its sides.
+        for _ in 0..200 {
+            let p = random.point();
+            let p = Point {
+                x: 0.25 + 0.5 * p.x,
+                y: 0.25 + 0.5 * p.y,
+            };
+            assert!((linear.interpolate(&p).unwrap() - field(&p)).abs() < 1e-9);
+        }
+
+        let (min, max) = (Point { x: 0.25, y: 0.25 }, Point { x: 0.75, y: 0.75 });
+        let raster = linear.raster(&min, &max, 7, 5);
+        assert_eq!(raster.len(), 35);
+        for (i, sample) in raster.into_iter().enumerate() {
+            let p = Point {
+                x: 0.25 + (i % 7) as f64 / 12.0,
+                y: 0.25 + (i / 7) as f64 / 8.0,
+            };
+            assert!((sample.unwrap() - field(&p)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn nothing_outside_the_hull() {
+        let points = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)].map(|(x, y)| Point { x, y });
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        let linear = Linear::new(&delaunay, &[1.0, 2.0, 3.0]).unwrap();
+
+        assert_eq!(linear.interpolate(&Point { x: 0.25, y: 0.25 }), Some(1.75));
+        assert_eq!(linear.interpolate(&Point { x: 1.0, y: 1.0 }), None);
+        assert_eq!(linear.interpolate(&Point { x: -0.1, y: 0.5 }), None);
+        let raster = linear.raster(&Point { x: 0.0, y: 0.5 }, &Point { x: 1.0, y: 0.5 }, 3, 1);
+        assert_eq!(raster[2], None);
+    }
+
+    #[test]
+    fn value_count() {
+        let points = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)].map(|(x, y)| Point { x, y });
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        assert_eq!(
+            Linear::new(&delaunay, &[1.0, 2.0]).err(),
+            Some(InterpolationError::ValueCount)
+        );
+    }
+}
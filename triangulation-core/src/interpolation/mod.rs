@@ -0,0 +1,26 @@
+use core::fmt;
+
+mod linear;
+mod natural_neighbor;
+
+pub use linear::Linear;
+pub use natural_neighbor::NaturalNeighbor;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolationError {
+    ValueCount,
+    GradientCount,
+}
+
+impl fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpolationError::ValueCount => write!(f, "exactly one value per point is required"),
+            InterpolationError::GradientCount => {
+                write!(f, "exactly one gradient per point is required")
+            }
+        }
+    }
+}
+
+impl core::error::Error for InterpolationError {}
@@ -0,0 +1,353 @@
+use super::InterpolationError;
+use crate::{Face, Graph, Point, Scalar, Vertex};
+use alloc::{vec, vec::Vec};
+
+struct Neighbor {
+    index: usize,
+    distance: f64,
+    sibson: f64,
+    laplace: f64,
+}
+
+enum Coordinates {
+    Vertex(usize),
+    Neighbors(Vec<Neighbor>),
+}
+
+/// Sibson and Laplace interpolation, at f64 points whatever the coordinates of the graph.
+pub struct NaturalNeighbor<'a, S = f64> {
+    graph: &'a Graph<S>,
+    values: &'a [f64],
+    gradients: Option<&'a [Point]>,
+}
+
+fn polygon_area(polygon: &[Point]) -> f64 {
+    let twice = (0..polygon.len())
+        .map(|i| {
+            let (p0, p1) = (&polygon[i], &polygon[(i + 1) % polygon.len()]);
+            p0.x * p1.y - p1.x * p0.y
+        })
+        .sum::<f64>();
+
+    twice.abs() / 2.0
+}
+
+fn position_f64<S: Scalar>(vertex: Vertex<'_, S>) -> Option<Point> {
+    vertex.position().map(|p| p.to_f64())
+}
+
+fn barycentric<S: Scalar>(face: Face<'_, S>, point: &Point) -> Option<Vec<Neighbor>> {
+    let vertices = face
+        .vertices()
+        .map(|v| Some((v.point_index()?, position_f64(v)?)))
+        .collect::<Option<Vec<_>>>()?;
+    let area = polygon_area(&[vertices[0].1, vertices[1].1, vertices[2].1]);
+
+    Some(
+        (0..3)
+            .map(|i| {
+                let (index, position) = vertices[i];
+                let (p1, p2) = (vertices[(i + 1) % 3].1, vertices[(i + 2) % 3].1);
+                let weight = polygon_area(&[*point, p1, p2]) / area;
+
+                Neighbor {
+                    index,
+                    distance: position.distance(point),
+                    sibson: weight,
+                    laplace: weight,
+                }
+            })
+            .collect(),
+    )
+}
+
+impl<'a, S: Scalar> NaturalNeighbor<'a, S> {
+    pub fn new(graph: &'a Graph<S>, values: &'a [f64]) -> Result<Self, InterpolationError> {
+        if values.len() != graph.points().len() {
+            return Err(InterpolationError::ValueCount);
+        }
+        Ok(NaturalNeighbor {
+            graph,
+            values,
+            gradients: None,
+        })
+    }
+
+    /// Gradients at every input point for `sibson_c1`, given or estimated by `gradients`.
+    pub fn with_gradients(mut self, gradients: &'a [Point]) -> Result<Self, InterpolationError> {
+        if gradients.len() != self.graph.points().len() {
+            return Err(InterpolationError::GradientCount);
+        }
+        self.gradients = Some(gradients);
+        Ok(self)
+    }
+
+    // Virtually inserts `point`: the faces whose circumcircle contains it form a cavity, the
+    // vertices on its boundary are the natural neighbors. Each one gives up to the new cell the
+    // part of its own cell bounded by the circumcenters of the cavity faces around it.
+    fn coordinates(&self, point: &Point) -> Option<Coordinates> {
+        let start = self.graph.locate_f64(point)?;
+
+        if let Some(vertex) = start
+            .vertices()
+            .find(|v| position_f64(*v).is_some_and(|p| p.x == point.x && p.y == point.y))
+        {
+            return vertex.point_index().map(Coordinates::Vertex);
+        }
+
+        let mut cavity = vec![start];
+        let mut stack = vec![start];
+        while let Some(face) = stack.pop() {
+            for edge in face.edges() {
+                let other = edge.twin().face();
+                if other.is_infinite() || cavity.contains(&other) {
+                    continue;
+                }
+
+                let mut positions = other.vertices().filter_map(position_f64);
+                let (p0, p1, p2) = (positions.next()?, positions.next()?, positions.next()?);
+                if point.in_circle(&p0, &p1, &p2) {
+                    cavity.push(other);
+                    stack.push(other);
+                }
+            }
+        }
+
+        let first = cavity
+            .iter()
+            .flat_map(|f| f.edges())
+            .find(|e| !cavity.contains(&e.twin().face()))?;
+
+        let mut neighbors = Vec::new();
+        let mut edge = first;
+        loop {
+            let (previous, vertex) = edge.vertices();
+            let mut stolen = vec![
+                Point::circumcenter(point, &position_f64(previous)?, &position_f64(vertex)?),
+                edge.face().circumcenter()?,
+            ];
+
+            let mut next = edge.next();
+            while cavity.contains(&next.twin().face()) {
+                next = next.twin().next();
+                stolen.push(next.face().circumcenter()?);
+            }
+
+            let following = next.vertices().1;
+            let new_center =
+                Point::circumcenter(point, &position_f64(vertex)?, &position_f64(following)?);
+            stolen.push(new_center);
+
+            if !stolen.iter().all(|p| p.x.is_finite() && p.y.is_finite()) {
+                return barycentric(start, point).map(Coordinates::Neighbors);
+            }
+
+            let distance = position_f64(vertex)?.distance(point);
+            neighbors.push(Neighbor {
+                index: vertex.point_index()?,
+                distance,
+                sibson: polygon_area(&stolen),
+                laplace: stolen[0].distance(&new_center) / distance,
+            });
+
+            edge = next;
+            if edge == first {
+                break;
+            }
+        }
+
+        Some(Coordinates::Neighbors(neighbors))
+    }
+
+    fn weighted(&self, point: &Point, weight: impl Fn(&Neighbor) -> f64) -> Option<f64> {
+        match self.coordinates(point)? {
+            Coordinates::Vertex(index) => Some(self.values[index]),
+            Coordinates::Neighbors(neighbors) => {
+                let total = neighbors.iter().map(&weight).sum::<f64>();
+                Some(
+                    neighbors
+                        .iter()
+                        .map(|n| weight(n) * self.values[n.index])
+                        .sum::<f64>()
+                        / total,
+                )
+            }
+        }
+    }
+
+    pub fn sibson(&self, point: &Point) -> Option<f64> {
+        self.weighted(point, |n| n.sibson)
+    }
+
+    pub fn laplace(&self, point: &Point) -> Option<f64> {
+        self.weighted(point, |n| n.laplace)
+    }
+
+    /// Sibson's C1 interpolant, blending the Sibson interpolation with the first order
+    /// estimates given by the gradients at every input point. None without gradients.
+    pub fn sibson_c1(&self, point: &Point) -> Option<f64> {
+        let gradients = self.gradients?;
+        let neighbors = match self.coordinates(point)? {
+            Coordinates::Vertex(index) => return Some(self.values[index]),
+            Coordinates::Neighbors(neighbors) => neighbors,
+        };
+
+        let total = neighbors.iter().map(|n| n.sibson).sum::<f64>();
+        let (mut linear, mut gradient, mut inverse, mut squared, mut distance) =
+            (0.0, 0.0, 0.0, 0.0, 0.0);
+
+        for n in &neighbors {
+            let weight = n.sibson / total;
+            let position = self.graph.points()[n.index].to_f64();
+            let g = gradients[n.index];
+            let estimate =
+                self.values[n.index] + g.x * (point.x - position.x) + g.y * (point.y - position.y);
+
+            linear += weight * self.values[n.index];
+            gradient += weight / n.distance * estimate;
+            inverse += weight / n.distance;
+            squared += weight * n.distance * n.distance;
+            distance += weight * n.distance;
+        }
+
+        let alpha = distance / inverse;
+        Some((alpha * linear + squared * gradient / inverse) / (alpha + squared))
+    }
+
+    /// Estimates the gradient at every input point by a least squares fit of a plane
+    /// through its Delaunay neighbors, weighted by their inverse squared distance.
+    pub fn gradients(&self) -> Vec<Point> {
+        let mut gradients = vec![Point { x: 0.0, y: 0.0 }; self.graph.points().len()];
+
+        for vertex in self.graph.vertices() {
+            let (Some(index), Some(position)) = (vertex.point_index(), position_f64(vertex)) else {
+                continue;
+            };
+
+            let (mut xx, mut xy, mut yy, mut xz, mut yz) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            for neighbor in vertex.neighbors() {
+                let (Some(i), Some(p)) = (neighbor.point_index(), position_f64(neighbor)) else {
+                    continue;
+                };
+
+                let (dx, dy) = (p.x - position.x, p.y - position.y);
+                let dz = self.values[i] - self.values[index];
+                let w = 1.0 / (dx * dx + dy * dy);
+
+                xx += w * dx * dx;
+                xy += w * dx * dy;
+                yy += w * dy * dy;
+                xz += w * dx * dz;
+                yz += w * dy * dz;
+            }
+
+            let determinant = xx * yy - xy * xy;
+            if determinant != 0.0 {
+                gradients[index] = Point {
+                    x: (yy * xz - xy * yz) / determinant,
+                    y: (xx * yz - xy * xz) / determinant,
+                };
+            }
+        }
+
+        gradients
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    fn field(p: &Point) -> f64 {
+        3.0 * p.x - 2.0 * p.y + 0.5
+    }
+
+    fn interior(random: &mut Random) -> Point {
+        let p = random.point();
+        Point {
+            x: 0.25 + 0.5 * p.x,
+            y: 0.25 + 0.5 * p.y,
+        }
+    }
+
+    #[test]
+    fn sibson_weights_are_the_stolen_area() {
+        let mut random = Random::new(0x6a09_e667_f3bc_c908);
+        let points = random.points(100);
+        let values = vec![0.0; points.len()];
+        let delaunay = Delaunay::from(points.clone().into()).unwrap();
+        let interpolation = NaturalNeighbor::new(&delaunay, &values).unwrap();
+
+        for _ in 0..50 {
+            let point = interior(&mut random);
+            let Some(Coordinates::Neighbors(neighbors)) = interpolation.coordinates(&point) else {
+                panic!("no natural neighbors");
+            };
+
+            // Once inserted for real, the cell of the point is made of the stolen parts.
+            let mut inserted = Delaunay::from(points.clone().into()).unwrap();
+            inserted.insert([point]).unwrap();
+            let cell = inserted
+                .vertices()
+                .find(|v| v.point_index() == Some(points.len()))
+                .unwrap()
+                .voronoi_cell();
+            let area = polygon_area(&cell);
+
+            let sum = neighbors.iter().map(|n| n.sibson / area).sum::<f64>();
+            assert!((sum - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn exact_at_the_points_and_for_linear_fields() {
+        let mut random = Random::new(0xbb67_ae85_84ca_a73b);
+        let points = random.points(100);
+        let values = points.iter().map(field).collect::<Vec<_>>();
+        let delaunay = Delaunay::from(points.clone().into()).unwrap();
+        let gradients = vec![Point { x: 3.0, y: -2.0 }; points.len()];
+        let interpolation = NaturalNeighbor::new(&delaunay, &values)
+            .unwrap()
+            .with_gradients(&gradients)
+            .unwrap();
+
+        for (point, value) in points.iter().zip(&values) {
+            assert_eq!(interpolation.sibson(point), Some(*value));
+            assert_eq!(interpolation.laplace(point), Some(*value));
+            assert_eq!(interpolation.sibson_c1(point), Some(*value));
+        }
+
+        for _ in 0..100 {
+            let point = interior(&mut random);
+            let expected = field(&point);
+            assert!((interpolation.sibson(&point).unwrap() - expected).abs() < 1e-9);
+            assert!((interpolation.laplace(&point).unwrap() - expected).abs() < 1e-9);
+            assert!((interpolation.sibson_c1(&point).unwrap() - expected).abs() < 1e-9);
+        }
+
+        // Interior points have neighbors all around, where the plane fits exactly.
+        for (vertex, gradient) in delaunay.vertices().zip(interpolation.gradients()) {
+            if vertex.position().is_some() && !vertex.is_on_hull() {
+                assert!((gradient.x - 3.0).abs() < 1e-9 && (gradient.y + 2.0).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn value_and_gradient_counts() {
+        let points = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)].map(|(x, y)| Point { x, y });
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        assert_eq!(
+            NaturalNeighbor::new(&delaunay, &[1.0]).err(),
+            Some(InterpolationError::ValueCount)
+        );
+
+        let interpolation = NaturalNeighbor::new(&delaunay, &[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(interpolation.sibson_c1(&Point { x: 0.2, y: 0.2 }), None);
+        assert_eq!(
+            interpolation.with_gradients(&[]).err(),
+            Some(InterpolationError::GradientCount)
+        );
+    }
+}
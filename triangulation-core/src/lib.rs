@@ -0,0 +1,34 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod graph;
+mod math;
+mod polygon;
+mod queue;
+#[cfg(test)]
+mod testing;
+
+pub mod alpha_shape;
+pub mod analysis;
+pub mod coloring;
+pub mod contour;
+pub mod empty_circle;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "rand")]
+pub mod generator;
+pub mod higher_order;
+pub mod interpolation;
+pub mod lloyd;
+pub mod map;
+pub mod navmesh;
+pub mod periodic;
+pub mod proximity;
+pub mod shortest_path;
+pub mod spherical;
+
+pub use graph::{
+    Attribute, Attributes, AttributesMut, CellMetrics, CellShape, Delaunay, Edge, Error, Face, Farthest, Graph,
+    Point, Regular, RegularError, Scalar, Vertex,
+};
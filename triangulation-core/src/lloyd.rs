@@ -0,0 +1,202 @@
+use crate::{AttributesMut, Delaunay, Error, Point, Vertex, polygon};
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LloydError {
+    Triangulation(Error),
+    /// The clip polygon is not convex, has no area or a corner that is not finite.
+    Clip,
+}
+
+impl fmt::Display for LloydError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LloydError::Triangulation(error) => error.fmt(f),
+            LloydError::Clip => write!(f, "the clip polygon must be convex"),
+        }
+    }
+}
+
+impl core::error::Error for LloydError {}
+
+impl From<Error> for LloydError {
+    fn from(error: Error) -> Self {
+        LloydError::Triangulation(error)
+    }
+}
+
+/// Lloyd relaxation towards a centroidal Voronoi tessellation, with cells clipped to a convex
+/// polygon.
+pub struct Lloyd {
+    delaunay: Delaunay,
+    clip: Vec<Point>,
+    fixed_boundary: bool,
+}
+
+struct Moments {
+    centroid: Point,
+    energy: f64,
+}
+
+// Centroid and second moment around `site` of a simple polygon.
+fn moments(polygon: &[Point], site: &Point) -> Option<Moments> {
+    let (mut area, mut cx, mut cy, mut energy) = (0.0, 0.0, 0.0, 0.0);
+    for i in 0..polygon.len() {
+        let (p0, p1) = (&polygon[i], &polygon[(i + 1) % polygon.len()]);
+        let (x0, y0, x1, y1) = (p0.x - site.x, p0.y - site.y, p1.x - site.x, p1.y - site.y);
+        let cross = x0 * y1 - x1 * y0;
+        area += cross;
+        cx += (x0 + x1) * cross;
+        cy += (y0 + y1) * cross;
+        energy += (x0 * x0 + x0 * x1 + x1 * x1 + y0 * y0 + y0 * y1 + y1 * y1) * cross;
+    }
+
+    (area != 0.0).then(|| Moments {
+        centroid: Point {
+            x: site.x + cx / (3.0 * area),
+            y: site.y + cy / (3.0 * area),
+        },
+        energy: (energy / 12.0).abs(),
+    })
+}
+
+impl Lloyd {
+    pub fn new(points: Box<[Point]>, clip: &[Point]) -> Result<Self, LloydError> {
+        if !polygon::is_convex(clip) {
+            return Err(LloydError::Clip);
+        }
+        Ok(Lloyd {
+            delaunay: Delaunay::from(points)?,
+            clip: clip.to_vec(),
+            fixed_boundary: false,
+        })
+    }
+
+    /// Keeps the sites on the convex hull in place.
+    pub fn with_fixed_boundary(mut self, fixed_boundary: bool) -> Self {
+        self.fixed_boundary = fixed_boundary;
+        self
+    }
+
+    pub fn delaunay(&self) -> &Delaunay {
+        &self.delaunay
+    }
+
+    /// Attributes of the triangulation, which follow the sites from step to step.
+    pub fn attributes_mut(&mut self) -> AttributesMut<'_> {
+        self.delaunay.attributes_mut()
+    }
+
+    pub fn points(&self) -> &[Point] {
+        self.delaunay.points()
+    }
+
+    fn cell(&self, vertex: Vertex<'_>) -> Option<Moments> {
+        moments(
+            &vertex.clipped_voronoi_cell(&self.clip),
+            &vertex.position()?,
+        )
+    }
+
+    /// Sum over all cells of the integrated squared distance to their site, for the current
+    /// sites. It decreases with every step and levels off as the sites converge.
+    pub fn energy(&self) -> f64 {
+        self.delaunay
+            .vertices()
+            .filter_map(|vertex| self.cell(vertex))
+            .map(|cell| cell.energy)
+            .sum()
+    }
+
+    /// Moves every site to the centroid of its cell and rebuilds the triangulation. Returns
+    /// the energy of the moved sites, as given by `energy`, or the error of the rebuild, which
+    /// leaves the sites as they were.
+    pub fn step(&mut self) -> Result<f64, Error> {
+        let mut points = self.points().to_vec();
+
+        for vertex in self.delaunay.vertices() {
+            let (Some(index), Some(cell)) = (vertex.point_index(), self.cell(vertex)) else {
+                continue;
+            };
+
+            if !(self.fixed_boundary && vertex.is_on_hull()) {
+                points[index] = cell.centroid;
+            }
+        }
+
+        self.delaunay.retriangulate(points.into_boxed_slice())?;
+        Ok(self.energy())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Random;
+    use alloc::vec;
+
+    fn square() -> [Point; 4] {
+        [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(x, y)| Point { x, y })
+    }
+
+    #[test]
+    fn energy_decreases() {
+        let points = Random::new(0x510e_527f_ade6_82d1).points(200);
+        let mut lloyd = Lloyd::new(points.into(), &square()).unwrap();
+
+        let mut energy = lloyd.energy();
+        for _ in 0..10 {
+            let next = lloyd.step().unwrap();
+            assert!(next <= energy * (1.0 + 1e-12));
+            assert_eq!(next, lloyd.energy());
+            energy = next;
+        }
+    }
+
+    #[test]
+    fn fixed_boundary() {
+        let mut points = square().to_vec();
+        points.extend(Random::new(0x9b05_688c_2b3e_6c1f).points(100));
+        let mut lloyd = Lloyd::new(points.clone().into(), &square())
+            .unwrap()
+            .with_fixed_boundary(true);
+        lloyd
+            .attributes_mut()
+            .set_vertex("index", |v| v.point_index().unwrap_or(usize::MAX));
+
+        for _ in 0..5 {
+            lloyd.step().unwrap();
+        }
+        let moved = |a: &Point, b: &Point| a.x != b.x || a.y != b.y;
+        let pairs = || lloyd.points().iter().zip(&points);
+        assert!(!pairs().take(4).any(|(a, b)| moved(a, b)));
+        assert!(pairs().skip(4).any(|(a, b)| moved(a, b)));
+        for vertex in lloyd.delaunay().vertices() {
+            let index = vertex.point_index().unwrap_or(usize::MAX);
+            assert_eq!(vertex.attribute::<usize>("index"), Some(&index));
+        }
+    }
+
+    #[test]
+    fn invalid_clip() {
+        let points = Random::new(1).points(10);
+        let [a, b, c, d] = square();
+        for clip in [
+            vec![a, b],
+            vec![a, b, c, d, b],
+            vec![a, c, b, d],
+            vec![a, b, a],
+        ] {
+            assert_eq!(
+                Lloyd::new(points.clone().into(), &clip).err(),
+                Some(LloydError::Clip)
+            );
+        }
+        let nan = Point {
+            x: f64::NAN,
+            y: 0.0,
+        };
+        assert!(Lloyd::new(points.into(), &[a, b, c, nan]).is_err());
+    }
+}
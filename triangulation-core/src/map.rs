@@ -0,0 +1,400 @@
+use crate::{Graph, Point, Scalar, math, queue::Open};
+use alloc::{collections::BinaryHeap, collections::VecDeque, vec, vec::Vec};
+
+/// Whittaker diagram biomes, by elevation and moisture.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Biome {
+    Ocean,
+    Lake,
+    Beach,
+    Snow,
+    Tundra,
+    Bare,
+    Scorched,
+    Taiga,
+    Shrubland,
+    TemperateDesert,
+    TemperateRainForest,
+    TemperateDeciduousForest,
+    Grassland,
+    TropicalRainForest,
+    TropicalSeasonalForest,
+    SubtropicalDesert,
+}
+
+impl Biome {
+    fn new(elevation: f64, moisture: f64) -> Self {
+        if elevation > 0.8 {
+            match moisture {
+                m if m > 0.5 => Biome::Snow,
+                m if m > 0.33 => Biome::Tundra,
+                m if m > 0.16 => Biome::Bare,
+                _ => Biome::Scorched,
+            }
+        } else if elevation > 0.6 {
+            match moisture {
+                m if m > 0.66 => Biome::Taiga,
+                m if m > 0.33 => Biome::Shrubland,
+                _ => Biome::TemperateDesert,
+            }
+        } else if elevation > 0.3 {
+            match moisture {
+                m if m > 0.83 => Biome::TemperateRainForest,
+                m if m > 0.5 => Biome::TemperateDeciduousForest,
+                m if m > 0.16 => Biome::Grassland,
+                _ => Biome::TemperateDesert,
+            }
+        } else {
+            match moisture {
+                m if m > 0.66 => Biome::TropicalRainForest,
+                m if m > 0.33 => Biome::TropicalSeasonalForest,
+                m if m > 0.16 => Biome::Grassland,
+                _ => Biome::SubtropicalDesert,
+            }
+        }
+    }
+}
+
+/// Voronoi cell of a vertex on the map.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    pub water: bool,
+    /// Water connected to the border of the map, the other water cells are lakes.
+    pub ocean: bool,
+    /// Land next to the ocean.
+    pub coast: bool,
+    /// From 0 at the sea to 1 on the highest cells.
+    pub elevation: f64,
+    /// From 0 to 1 on land, highest next to lakes and rivers.
+    pub moisture: f64,
+    /// Neighbor the water of the cell runs to, on the way down to the ocean.
+    pub downslope: Option<usize>,
+    /// Number of rivers running from the cell to `downslope`.
+    pub flow: u32,
+    pub biome: Biome,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Parameters {
+    pub seed: u64,
+    /// Noise level under which cells are water, higher for smaller islands.
+    pub sea_level: f64,
+    /// Number of noise features across the map.
+    pub frequency: f64,
+    pub rivers: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters {
+            seed: 0,
+            sea_level: 0.2,
+            frequency: 4.0,
+            rivers: 30,
+        }
+    }
+}
+
+/// Island map made of the Voronoi cells of a triangulation, in the manner of Amit Patel's
+/// polygon map generation. The same parameters always give the same map.
+#[derive(Debug, Clone)]
+pub struct Map {
+    cells: Vec<Cell>,
+}
+
+fn splitmix(mut z: u64) -> u64 {
+    z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn hash(seed: u64, x: i64, y: i64) -> u64 {
+    splitmix(seed ^ splitmix(x as u64 ^ splitmix(y as u64)))
+}
+
+// Value noise between 0 and 1, smoothly interpolated between random values on integer points.
+fn value_noise(seed: u64, x: f64, y: f64) -> f64 {
+    let (x0, y0) = (math::floor(x), math::floor(y));
+    let smooth = |t: f64| t * t * (3.0 - 2.0 * t);
+    let (tx, ty) = (smooth(x - x0), smooth(y - y0));
+    let value = |dx: i64, dy: i64| {
+        (hash(seed, x0 as i64 + dx, y0 as i64 + dy) >> 11) as f64 / (1u64 << 53) as f64
+    };
+
+    let bottom = value(0, 0) + (value(1, 0) - value(0, 0)) * tx;
+    let top = value(0, 1) + (value(1, 1) - value(0, 1)) * tx;
+    bottom + (top - bottom) * ty
+}
+
+fn fractal_noise(seed: u64, x: f64, y: f64) -> f64 {
+    let (mut sum, mut total, mut scale) = (0.0, 0.0, 1.0);
+    for octave in 0..4 {
+        sum += value_noise(splitmix(seed.wrapping_add(octave)), x * scale, y * scale) / scale;
+        total += 1.0 / scale;
+        scale *= 2.0;
+    }
+    sum / total
+}
+
+// From 0 to 1 across the range, or in the middle of a range with no extent.
+fn normalize(value: f64, min: f64, max: f64) -> f64 {
+    let extent = max - min;
+    if extent > 0.0 && extent.is_finite() {
+        (value - min) / extent
+    } else {
+        0.5
+    }
+}
+
+// Replaces values by their rank, spread from 0 to 1 and reshaped by `f`.
+fn redistribute(values: &mut [(usize, f64)], f: impl Fn(f64) -> f64) {
+    values.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let last = values.len().saturating_sub(1).max(1) as f64;
+    for (rank, value) in values.iter_mut().enumerate() {
+        value.1 = f(rank as f64 / last);
+    }
+}
+
+impl Map {
+    pub fn new<S: Scalar>(graph: &Graph<S>, parameters: &Parameters) -> Self {
+        let water = Cell {
+            water: true,
+            ocean: false,
+            coast: false,
+            elevation: 0.0,
+            moisture: 0.0,
+            downslope: None,
+            flow: 0,
+            biome: Biome::Ocean,
+        };
+        let mut cells = vec![water; graph.vertex_count()];
+        let neighbors = |i: usize| {
+            graph
+                .vertex(i)
+                .neighbors()
+                .filter(|v| v.position().is_some())
+                .map(|v| v.id())
+        };
+
+        // Land where the noise at the centroid of the cell rises above the sea level, lowered
+        // towards the border of the map. Unbounded cells are always water.
+        let (mut min, mut max) = (
+            Point {
+                x: f64::INFINITY,
+                y: f64::INFINITY,
+            },
+            Point {
+                x: f64::NEG_INFINITY,
+                y: f64::NEG_INFINITY,
+            },
+        );
+        for p in graph.points().iter().map(|p| p.to_f64()) {
+            (min.x, min.y) = (min.x.min(p.x), min.y.min(p.y));
+            (max.x, max.y) = (max.x.max(p.x), max.y.max(p.y));
+        }
+        for (i, metrics) in graph.voronoi_metrics().iter().enumerate() {
+            if let Some(shape) = &metrics.shape {
+                let x = normalize(shape.centroid.x, min.x, max.x);
+                let y = normalize(shape.centroid.y, min.y, max.y);
+                let (dx, dy) = (2.0 * x - 1.0, 2.0 * y - 1.0);
+                let noise = fractal_noise(
+                    parameters.seed,
+                    x * parameters.frequency,
+                    y * parameters.frequency,
+                );
+                cells[i].water = noise * (1.0 - dx * dx - dy * dy) <= parameters.sea_level;
+            }
+        }
+
+        // Water reached from the border is ocean, the rest is lakes.
+        let mut queue = graph
+            .hull()
+            .filter(|v| v.position().is_some())
+            .map(|v| v.id())
+            .collect::<VecDeque<_>>();
+        for &i in &queue {
+            cells[i].water = true;
+            cells[i].ocean = true;
+        }
+        while let Some(i) = queue.pop_front() {
+            for j in neighbors(i) {
+                if cells[j].water && !cells[j].ocean {
+                    cells[j].ocean = true;
+                    queue.push_back(j);
+                }
+            }
+        }
+        for i in 1..cells.len() {
+            cells[i].coast = !cells[i].water && neighbors(i).any(|j| cells[j].ocean);
+        }
+        cells[0].ocean = true;
+
+        // Distance to the ocean along the edges, shorter across lakes so that they stay flat.
+        // Every cell drains to the neighbor it is reached from.
+        let mut distances = vec![f64::INFINITY; cells.len()];
+        let mut open = BinaryHeap::new();
+        for i in 1..cells.len() {
+            if cells[i].ocean {
+                distances[i] = 0.0;
+                open.push(Open {
+                    cost: 0.0,
+                    index: i,
+                });
+            }
+        }
+        while let Some(Open { cost, index }) = open.pop() {
+            if cost > distances[index] {
+                continue;
+            }
+            let site = graph.vertex(index).position().unwrap();
+            for j in neighbors(index) {
+                let length = site.distance(&graph.vertex(j).position().unwrap());
+                let distance = cost + if cells[j].water { 0.1 * length } else { length };
+                if distance < distances[j] {
+                    distances[j] = distance;
+                    cells[j].downslope = Some(index);
+                    open.push(Open {
+                        cost: distance,
+                        index: j,
+                    });
+                }
+            }
+        }
+
+        // Ranks give as many cells at every height, reshaped to have more lowlands.
+        let mut elevations = (1..cells.len())
+            .filter(|&i| !cells[i].ocean)
+            .map(|i| (i, distances[i]))
+            .collect::<Vec<_>>();
+        redistribute(&mut elevations, |x| 1.0 - math::sqrt(1.0 - x));
+        for (i, elevation) in elevations {
+            cells[i].elevation = elevation;
+        }
+
+        // Rivers start from random highlands and run down to the ocean.
+        let mut springs = (1..cells.len())
+            .filter(|&i| !cells[i].water && (0.3..0.9).contains(&cells[i].elevation))
+            .collect::<Vec<_>>();
+        springs.sort_by_key(|&i| hash(parameters.seed, i as i64, -1));
+        for &spring in springs.iter().take(parameters.rivers) {
+            let mut i = spring;
+            while let Some(next) = cells[i].downslope {
+                cells[i].flow += 1;
+                i = next;
+            }
+        }
+
+        // Land is wetter the fewer cells away from fresh water it is.
+        let mut hops = vec![usize::MAX; cells.len()];
+        let mut queue = (1..cells.len())
+            .filter(|&i| (cells[i].water && !cells[i].ocean) || cells[i].flow > 0)
+            .collect::<VecDeque<_>>();
+        for &i in &queue {
+            hops[i] = 0;
+        }
+        while let Some(i) = queue.pop_front() {
+            for j in neighbors(i) {
+                if !cells[j].water && hops[j] == usize::MAX {
+                    hops[j] = hops[i] + 1;
+                    queue.push_back(j);
+                }
+            }
+        }
+        let mut moistures = (1..cells.len())
+            .filter(|&i| !cells[i].water)
+            .map(|i| (i, -(hops[i] as f64)))
+            .collect::<Vec<_>>();
+        redistribute(&mut moistures, |x| x);
+        for (i, moisture) in moistures {
+            cells[i].moisture = moisture;
+        }
+
+        for cell in cells.iter_mut() {
+            cell.biome = if cell.ocean {
+                Biome::Ocean
+            } else if cell.water {
+                Biome::Lake
+            } else if cell.coast {
+                Biome::Beach
+            } else {
+                Biome::new(cell.elevation, cell.moisture)
+            };
+        }
+
+        Map { cells }
+    }
+
+    /// Cells by vertex id. The infinite vertex is ocean.
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    /// Vertex ids at both ends and flow of every river segment.
+    pub fn rivers(&self) -> impl Iterator<Item = (usize, usize, u32)> + '_ {
+        self.cells.iter().enumerate().filter_map(|(i, cell)| {
+            let to = cell.downslope?;
+            (cell.flow > 0).then_some((i, to, cell.flow))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    #[test]
+    fn island() {
+        let points = Random::new(0x6a09_e667_f3bc_c908).points(1000);
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        let parameters = Parameters {
+            seed: 42,
+            ..Parameters::default()
+        };
+        let map = Map::new(&delaunay, &parameters);
+        let cells = map.cells();
+
+        assert_eq!(cells.len(), delaunay.vertex_count());
+        assert!(cells[0].ocean);
+        assert!(delaunay.hull().all(|v| cells[v.id()].ocean));
+        assert!(cells.iter().any(|cell| !cell.water));
+        for (i, cell) in cells.iter().enumerate() {
+            assert!((0.0..=1.0).contains(&cell.elevation));
+            assert!((0.0..=1.0).contains(&cell.moisture));
+            assert_eq!(cell.biome == Biome::Ocean, cell.ocean);
+
+            // Every cell drains to the ocean.
+            let mut j = i;
+            for _ in 0..cells.len() {
+                let Some(next) = cells[j].downslope else {
+                    break;
+                };
+                j = next;
+            }
+            assert!(cells[j].ocean);
+        }
+        assert!(map.rivers().count() > 0);
+        assert!(
+            map.rivers()
+                .all(|(from, to, _)| cells[from].downslope == Some(to))
+        );
+
+        // The same parameters give the same map, another seed a different one.
+        assert_eq!(Map::new(&delaunay, &parameters).cells(), cells);
+        let other = Parameters {
+            seed: 43,
+            ..parameters
+        };
+        assert_ne!(Map::new(&delaunay, &other).cells(), cells);
+    }
+
+    #[test]
+    fn no_extent() {
+        assert_eq!(normalize(0.25, 0.0, 0.5), 0.5);
+        assert_eq!(normalize(1.0, 1.0, 1.0), 0.5);
+        assert_eq!(normalize(0.0, f64::NEG_INFINITY, f64::INFINITY), 0.5);
+    }
+}
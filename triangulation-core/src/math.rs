@@ -0,0 +1,69 @@
+#[cfg(feature = "std")]
+pub fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(feature = "std")]
+pub fn sin(x: f64) -> f64 {
+    x.sin()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+#[cfg(feature = "std")]
+pub fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+#[cfg(feature = "std")]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+#[cfg(not(feature = "std"))]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x)
+}
+
+#[cfg(feature = "std")]
+pub fn floor(x: f64) -> f64 {
+    x.floor()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn floor(x: f64) -> f64 {
+    libm::floor(x)
+}
+
+#[cfg(all(feature = "rand", feature = "std"))]
+pub fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+#[cfg(all(feature = "rand", not(feature = "std")))]
+pub fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+#[cfg(all(feature = "rand", feature = "std"))]
+pub fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+#[cfg(all(feature = "rand", not(feature = "std")))]
+pub fn ceil(x: f64) -> f64 {
+    libm::ceil(x)
+}
@@ -0,0 +1,294 @@
+use crate::{Edge, Face, Graph, Point, Scalar, queue::Open};
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+
+/// Path finding over the finite faces of a triangulation. Agents move between faces through
+/// shared edges, except for hull edges and the edges marked as walls.
+#[derive(Debug)]
+pub struct Navmesh<'a, S = f64> {
+    graph: &'a Graph<S>,
+    walls: Vec<bool>,
+    radius: f64,
+}
+
+fn cross(o: &Point, a: &Point, b: &Point) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+fn midpoint(a: &Point, b: &Point) -> Point {
+    Point {
+        x: (a.x + b.x) / 2.0,
+        y: (a.y + b.y) / 2.0,
+    }
+}
+
+fn same(a: &Point, b: &Point) -> bool {
+    a.x == b.x && a.y == b.y
+}
+
+// Shortest path through the portals, each given as its left and right end seen when walking
+// the corridor, by the simple stupid funnel algorithm.
+fn funnel(portals: &[(Point, Point)]) -> Vec<Point> {
+    let mut path = vec![portals[0].0];
+    let (mut apex, mut left, mut right) = (portals[0].0, portals[0].0, portals[0].1);
+    let (mut left_index, mut right_index) = (0, 0);
+
+    let mut i = 1;
+    while i < portals.len() {
+        let (next_left, next_right) = portals[i];
+
+        // Narrow the funnel from the right, or turn around its left side when crossing it.
+        if cross(&apex, &right, &next_right) >= 0.0 {
+            if same(&apex, &right) || cross(&apex, &left, &next_right) < 0.0 {
+                right = next_right;
+                right_index = i;
+            } else {
+                apex = left;
+                path.push(apex);
+                right = apex;
+                right_index = left_index;
+                i = left_index + 1;
+                continue;
+            }
+        }
+
+        if cross(&apex, &left, &next_left) <= 0.0 {
+            if same(&apex, &left) || cross(&apex, &right, &next_left) > 0.0 {
+                left = next_left;
+                left_index = i;
+            } else {
+                apex = right;
+                path.push(apex);
+                left = apex;
+                left_index = right_index;
+                i = right_index + 1;
+                continue;
+            }
+        }
+
+        i += 1;
+    }
+
+    let goal = portals[portals.len() - 1].0;
+    if !same(&path[path.len() - 1], &goal) {
+        path.push(goal);
+    }
+    path
+}
+
+impl<'a, S: Scalar> Navmesh<'a, S> {
+    pub fn new(graph: &'a Graph<S>) -> Self {
+        Navmesh {
+            graph,
+            walls: vec![false; graph.edge_count()],
+            radius: 0.0,
+        }
+    }
+
+    /// Blocks the edges for which `f` is true, for example the ones with a `wall` attribute.
+    pub fn with_walls<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(Edge<'a, S>) -> bool,
+    {
+        self.walls = self.graph.edges().map(f).collect();
+        self
+    }
+
+    /// Radius of the agent. Portals narrower than the agent are closed and paths keep this
+    /// distance from the ends of the portals they go through.
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn graph(&self) -> &'a Graph<S> {
+        self.graph
+    }
+
+    fn endpoints(edge: Edge<'a, S>) -> Option<(Point, Point)> {
+        let (v0, v1) = edge.vertices();
+        Some((v0.position()?.to_f64(), v1.position()?.to_f64()))
+    }
+
+    /// Whether an agent can move across `edge`.
+    pub fn is_passable(&self, edge: Edge<'a, S>) -> bool {
+        !self.walls[edge.id()]
+            && !edge.face().is_infinite()
+            && !edge.twin().face().is_infinite()
+            && Self::endpoints(edge).is_some_and(|(p0, p1)| p0.distance(&p1) >= 2.0 * self.radius)
+    }
+
+    /// Faces crossed by the shortest path found by A* from `start` to `goal`, moving between
+    /// the midpoints of the edges. None if either point is outside of the triangulation or
+    /// walls separate them.
+    pub fn corridor(&self, start: &Point<S>, goal: &Point<S>) -> Option<Vec<Face<'a, S>>> {
+        let first = self.graph.locate(start)?;
+        let last = self.graph.locate(goal)?;
+        let goal = goal.to_f64();
+
+        let mut costs = vec![f64::INFINITY; self.graph.face_count()];
+        let mut entries = vec![start.to_f64(); self.graph.face_count()];
+        let mut previous = vec![None; self.graph.face_count()];
+        let mut open = BinaryHeap::new();
+
+        costs[first.id()] = 0.0;
+        open.push(Open {
+            cost: entries[first.id()].distance(&goal),
+            index: first.id(),
+        });
+
+        while let Some(Open { cost, index: face }) = open.pop() {
+            if face == last.id() {
+                break;
+            }
+            let entry = entries[face];
+            if cost > costs[face] + entry.distance(&goal) {
+                continue;
+            }
+
+            for edge in self.graph.face(face).edges() {
+                if !self.is_passable(edge) {
+                    continue;
+                }
+                let (p0, p1) = Self::endpoints(edge)?;
+                let next = edge.twin().face().id();
+                let portal = midpoint(&p0, &p1);
+                let next_cost = costs[face] + entry.distance(&portal);
+
+                if next_cost < costs[next] {
+                    costs[next] = next_cost;
+                    entries[next] = portal;
+                    previous[next] = Some(face);
+                    open.push(Open {
+                        cost: next_cost + portal.distance(&goal),
+                        index: next,
+                    });
+                }
+            }
+        }
+
+        let mut faces = vec![last];
+        while let Some(face) = faces.last().and_then(|f| previous[f.id()]) {
+            faces.push(self.graph.face(face));
+        }
+        faces.reverse();
+        (faces[0] == first).then_some(faces)
+    }
+
+    // Left and right ends of the edge shared by two consecutive faces, moved inwards by the
+    // agent radius.
+    fn portal(&self, from: Face<'a, S>, to: Face<'a, S>) -> Option<(Point, Point)> {
+        let edge = from.edges().find(|e| e.twin().face() == to)?;
+        let (v0, v1) = edge.vertices();
+        let (p0, p1) = Self::endpoints(edge)?;
+        let opposite = from.vertices().find(|&v| v != v0 && v != v1)?;
+        let (left, right) = if cross(&opposite.position()?.to_f64(), &p0, &p1) > 0.0 {
+            (p1, p0)
+        } else {
+            (p0, p1)
+        };
+
+        let t = self.radius / left.distance(&right);
+        let inset = |a: &Point, b: &Point| Point {
+            x: a.x + (b.x - a.x) * t,
+            y: a.y + (b.y - a.y) * t,
+        };
+        Some((inset(&left, &right), inset(&right, &left)))
+    }
+
+    /// Shortest polyline from `start` to `goal` through the corridor, with a point at every
+    /// corner it turns around.
+    pub fn path(&self, start: &Point<S>, goal: &Point<S>) -> Option<Vec<Point>> {
+        let corridor = self.corridor(start, goal)?;
+        let (start, goal) = (start.to_f64(), goal.to_f64());
+
+        let mut portals = vec![(start, start)];
+        for faces in corridor.windows(2) {
+            portals.push(self.portal(faces[0], faces[1])?);
+        }
+        portals.push((goal, goal));
+        Some(funnel(&portals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    fn square() -> Delaunay {
+        let corners = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(x, y)| Point { x, y });
+        let mut points = corners.to_vec();
+        points.extend(Random::new(0x2545_f491_4f6c_dd1d).points(300));
+        Delaunay::from(points.into()).unwrap()
+    }
+
+    // Height at which the segment from `a` to `b` crosses the vertical line at x = 0.5.
+    fn crossing(a: &Point, b: &Point) -> Option<f64> {
+        ((a.x - 0.5) * (b.x - 0.5) < 0.0).then(|| a.y + (0.5 - a.x) / (b.x - a.x) * (b.y - a.y))
+    }
+
+    // Wall along x = 0.5, from the bottom of the square up to `top`.
+    fn below(top: f64) -> impl FnMut(Edge<'_>) -> bool {
+        move |edge| {
+            let (v0, v1) = edge.vertices();
+            match (v0.position(), v1.position()) {
+                (Some(p0), Some(p1)) => crossing(&p0, &p1).is_some_and(|y| y < top),
+                _ => false,
+            }
+        }
+    }
+
+    #[test]
+    fn straight_path_without_walls() {
+        // Faces of a strip follow one another, so the corridor holds the straight line.
+        let points = (0..=10)
+            .flat_map(|i| [(i as f64, 0.0), (i as f64 + 0.5, 1.0)])
+            .map(|(x, y)| Point { x, y })
+            .collect::<Vec<_>>();
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        let navmesh = Navmesh::new(&delaunay);
+        let (start, goal) = (Point { x: 0.6, y: 0.5 }, Point { x: 9.6, y: 0.8 });
+
+        assert_eq!(navmesh.corridor(&start, &goal).unwrap().len(), 19);
+        let path = navmesh.path(&start, &goal).unwrap();
+        assert_eq!(path.len(), 2);
+        assert!(same(&path[0], &start) && same(&path[1], &goal));
+    }
+
+    #[test]
+    fn path_around_a_wall() {
+        let delaunay = square();
+        let navmesh = Navmesh::new(&delaunay).with_walls(below(0.8));
+        let (start, goal) = (Point { x: 0.2, y: 0.3 }, Point { x: 0.8, y: 0.3 });
+
+        let path = navmesh.path(&start, &goal).unwrap();
+        assert!(same(&path[0], &start) && same(&path[path.len() - 1], &goal));
+        let length = path.windows(2).map(|w| w[0].distance(&w[1])).sum::<f64>();
+        assert!(length > 2.0 * Point { x: 0.5, y: 0.7 }.distance(&start));
+        assert!(
+            path.windows(2)
+                .filter_map(|w| crossing(&w[0], &w[1]))
+                .all(|y| y > 0.7)
+        );
+    }
+
+    #[test]
+    fn blocked_paths() {
+        let delaunay = square();
+        let (start, goal) = (Point { x: 0.2, y: 0.3 }, Point { x: 0.8, y: 0.3 });
+
+        let walled = Navmesh::new(&delaunay).with_walls(below(2.0));
+        assert!(walled.corridor(&start, &goal).is_none());
+        assert!(
+            Navmesh::new(&delaunay)
+                .with_radius(1.0)
+                .path(&start, &goal)
+                .is_none()
+        );
+        assert!(
+            Navmesh::new(&delaunay)
+                .path(&start, &Point { x: 1.5, y: 0.5 })
+                .is_none()
+        );
+    }
+}
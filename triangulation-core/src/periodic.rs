@@ -0,0 +1,206 @@
+use crate::{Delaunay, Error, Point, math};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::fmt;
+
+/// Translation by whole periods of the unit square.
+pub type Offset = [i32; 2];
+
+/// A point index and the tile it is taken from.
+pub type Corner = (usize, Offset);
+
+/// An edge from `from` to the copy of `to` translated by `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeriodicEdge {
+    pub from: usize,
+    pub to: usize,
+    pub offset: Offset,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodicError {
+    Triangulation(Error),
+    /// The points leave an empty circle too wide for the neighboring tiles, so some edges
+    /// have no twin.
+    TooSparse,
+}
+
+impl fmt::Display for PeriodicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeriodicError::Triangulation(error) => error.fmt(f),
+            PeriodicError::TooSparse => write!(f, "the points are too sparse to wrap"),
+        }
+    }
+}
+
+impl core::error::Error for PeriodicError {}
+
+impl From<Error> for PeriodicError {
+    fn from(error: Error) -> Self {
+        PeriodicError::Triangulation(error)
+    }
+}
+
+/// Delaunay triangulation of the flat torus made by wrapping the unit square.
+///
+/// The points are triangulated together with their eight translated copies and every triangle
+/// of the torus is kept once. This needs points spread densely enough that no empty circle is
+/// wider than half the square.
+#[derive(Debug)]
+pub struct Periodic {
+    points: Box<[Point]>,
+    triangles: Vec<[Corner; 3]>,
+    halfedges: Vec<usize>,
+}
+
+// The same edge seen from its start point, whatever the tile of the triangle holding it.
+fn key(from: &Corner, to: &Corner) -> (usize, usize, Offset) {
+    (from.0, to.0, [to.1[0] - from.1[0], to.1[1] - from.1[1]])
+}
+
+impl Periodic {
+    /// Triangulates points of the unit square, wrapping the ones outside of it.
+    pub fn from(mut points: Box<[Point]>) -> Result<Self, PeriodicError> {
+        for p in points.iter_mut() {
+            p.x -= math::floor(p.x);
+            p.y -= math::floor(p.y);
+        }
+
+        let tiles = (-1..=1)
+            .flat_map(|dx| (-1..=1).map(move |dy| [dx, dy]))
+            .collect::<Vec<Offset>>();
+        let delaunay = tiles
+            .iter()
+            .flat_map(|offset| {
+                points.iter().map(|p| Point {
+                    x: p.x + offset[0] as f64,
+                    y: p.y + offset[1] as f64,
+                })
+            })
+            .collect::<Box<[_]>>();
+        let delaunay = Delaunay::from(delaunay)?;
+
+        // Keep a triangle when its smallest corner, which is the same in all its copies, lies
+        // in the original square.
+        let triangles = delaunay
+            .faces()
+            .filter(|face| !face.is_infinite())
+            .filter_map(|face| {
+                let mut corners = face.vertices().map(|v| {
+                    let i = v.point_index().unwrap();
+                    (i % points.len(), tiles[i / points.len()])
+                });
+                let corners = [corners.next()?, corners.next()?, corners.next()?];
+                (corners.iter().min()?.1 == [0, 0]).then_some(corners)
+            })
+            .collect::<Vec<_>>();
+
+        let ids = triangles
+            .iter()
+            .enumerate()
+            .flat_map(|(t, corners)| {
+                (0..3).map(move |i| (key(&corners[i], &corners[(i + 1) % 3]), 3 * t + i))
+            })
+            .collect::<BTreeMap<_, _>>();
+        // Too few points leave triangles of the copies reaching past the neighboring tiles,
+        // whose edges then have no twin.
+        let halfedges = triangles
+            .iter()
+            .flat_map(|corners| {
+                let ids = &ids;
+                (0..3).map(move |i| ids.get(&key(&corners[(i + 1) % 3], &corners[i])).copied())
+            })
+            .collect::<Option<_>>()
+            .ok_or(PeriodicError::TooSparse)?;
+
+        Ok(Periodic {
+            points,
+            triangles,
+            halfedges,
+        })
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Corners of every triangle, in the same winding as `Delaunay` faces.
+    pub fn triangles(&self) -> &[[Corner; 3]] {
+        &self.triangles
+    }
+
+    /// For the half-edge `3 * t + i` from corner `i` to corner `i + 1` of triangle `t`, the
+    /// half-edge going the other way in the neighboring triangle.
+    pub fn halfedges(&self) -> &[usize] {
+        &self.halfedges
+    }
+
+    pub fn position(&self, corner: &Corner) -> Point {
+        let p = self.points[corner.0];
+        Point {
+            x: p.x + corner.1[0] as f64,
+            y: p.y + corner.1[1] as f64,
+        }
+    }
+
+    /// Every edge once.
+    pub fn edges(&self) -> impl Iterator<Item = PeriodicEdge> + '_ {
+        self.triangles
+            .iter()
+            .flat_map(|corners| (0..3).map(move |i| key(&corners[i], &corners[(i + 1) % 3])))
+            .filter(|&(from, to, offset)| (from, to, offset) < (to, from, [-offset[0], -offset[1]]))
+            .map(|(from, to, offset)| PeriodicEdge { from, to, offset })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Random;
+
+    #[test]
+    fn torus() {
+        let mut points = Random::new(0x1f83_d9ab_fb41_bd6b).points(300);
+        // Points outside of the square are wrapped into it.
+        points[0].x += 2.0;
+        points[1].y -= 1.0;
+        let periodic = Periodic::from(points.into()).unwrap();
+        let n = periodic.points().len();
+        assert!(
+            periodic
+                .points()
+                .iter()
+                .all(|p| (0.0..1.0).contains(&p.x) && (0.0..1.0).contains(&p.y))
+        );
+
+        // Euler's formula on the torus gives twice as many triangles as points.
+        assert_eq!(periodic.triangles().len(), 2 * n);
+        assert_eq!(periodic.edges().count(), 3 * n);
+
+        let halfedges = periodic.halfedges();
+        for (e, &twin) in halfedges.iter().enumerate() {
+            assert_ne!(e, twin);
+            assert_eq!(halfedges[twin], e);
+        }
+
+        // The triangles tile an area of one square.
+        let area = periodic
+            .triangles()
+            .iter()
+            .map(|corners| {
+                let [a, b, c] = corners.map(|corner| periodic.position(&corner));
+                ((b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)) / 2.0
+            })
+            .sum::<f64>();
+        assert!((area.abs() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn invalid_points() {
+        let points = [(0.1, 0.1), (f64::NAN, 0.5), (0.5, 0.9)].map(|(x, y)| Point { x, y });
+        assert!(matches!(
+            Periodic::from(Box::new(points)),
+            Err(PeriodicError::Triangulation(_))
+        ));
+    }
+}
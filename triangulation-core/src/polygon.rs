@@ -0,0 +1,80 @@
+use crate::Point;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone)]
+pub struct Polygon {
+    pub exterior: Vec<Point>,
+    pub holes: Vec<Vec<Point>>,
+}
+
+pub(crate) fn signed_area(ring: &[Point]) -> f64 {
+    (0..ring.len())
+        .map(|i| {
+            let (p0, p1) = (&ring[i], &ring[(i + 1) % ring.len()]);
+            p0.x * p1.y - p1.x * p0.y
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+// Whether `ring` is a convex polygon with a non-zero area and finite corners, every corner
+// being on the same side of every side.
+pub(crate) fn is_convex(ring: &[Point]) -> bool {
+    let area = signed_area(ring);
+    if ring.len() < 3 || !area.is_finite() || area == 0.0 {
+        return false;
+    }
+
+    (0..ring.len()).all(|i| {
+        let (p0, p1) = (&ring[i], &ring[(i + 1) % ring.len()]);
+        ring.iter().all(|p| {
+            let cross = (p1.x - p0.x) * (p.y - p0.y) - (p1.y - p0.y) * (p.x - p0.x);
+            cross * area >= 0.0
+        })
+    })
+}
+
+pub(crate) fn contains(ring: &[Point], point: &Point) -> bool {
+    let mut inside = false;
+    for i in 0..ring.len() {
+        let (p0, p1) = (&ring[i], &ring[(i + 1) % ring.len()]);
+        if (p0.y > point.y) != (p1.y > point.y)
+            && point.x < p0.x + (point.y - p0.y) / (p1.y - p0.y) * (p1.x - p0.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+// Builds polygons from rings traced along face boundaries. Faces wind the same way as
+// `Point::is_ccw`, which gives a negative area: rings winding like them are exteriors, the
+// others are holes, given to the smallest exterior around them.
+pub(crate) fn assemble(rings: impl Iterator<Item = Vec<Point>>) -> Vec<Polygon> {
+    let (exteriors, holes): (Vec<_>, Vec<_>) = rings.partition(|ring| signed_area(ring) < 0.0);
+
+    let mut polygons = exteriors
+        .into_iter()
+        .map(|exterior| Polygon {
+            exterior,
+            holes: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+
+    for hole in holes {
+        let owner = polygons
+            .iter_mut()
+            .filter(|p| contains(&p.exterior, &hole[0]))
+            .min_by(|a, b| {
+                signed_area(&b.exterior)
+                    .partial_cmp(&signed_area(&a.exterior))
+                    .unwrap()
+            });
+
+        if let Some(owner) = owner {
+            owner.holes.push(hole);
+        }
+    }
+
+    polygons
+}
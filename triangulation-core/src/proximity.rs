@@ -0,0 +1,221 @@
+use crate::{Edge, Graph, Point, Scalar};
+use alloc::{vec, vec::Vec};
+
+fn endpoints<S: Scalar>(edge: Edge<'_, S>) -> Option<(Point<S>, Point<S>)> {
+    let (v0, v1) = edge.vertices();
+    Some((v0.position()?, v1.position()?))
+}
+
+fn finite_edges<S: Scalar>(graph: &Graph<S>) -> impl Iterator<Item = Edge<'_, S>> {
+    graph.edges().filter(|&edge| endpoints(edge).is_some())
+}
+
+fn find(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// Euclidean minimum spanning tree, by increasing length.
+pub fn minimum_spanning_tree<S: Scalar>(graph: &Graph<S>) -> Vec<Edge<'_, S>> {
+    let mut edges = finite_edges(graph)
+        .filter_map(|edge| {
+            let (p0, p1) = endpoints(edge)?;
+            Some((p0.distance_squared(&p1), edge))
+        })
+        .collect::<Vec<_>>();
+    edges.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let mut parents = (0..graph.vertex_count()).collect::<Vec<_>>();
+    let mut tree = Vec::with_capacity(graph.vertex_count().saturating_sub(2));
+    for (_, edge) in edges {
+        let (v0, v1) = edge.vertices();
+        let (a, b) = (find(&mut parents, v0.id()), find(&mut parents, v1.id()));
+        if a != b {
+            parents[a] = b;
+            tree.push(edge);
+        }
+    }
+    tree
+}
+
+/// Edges whose diametral circle has no other point strictly inside.
+pub fn gabriel<S: Scalar>(graph: &Graph<S>) -> Vec<Edge<'_, S>> {
+    // In a Delaunay triangulation only the two opposite vertices can be inside the circle.
+    finite_edges(graph)
+        .filter(|&edge| {
+            let (p0, p1) = endpoints(edge).unwrap();
+            let (p0, p1) = (p0.to_f64(), p1.to_f64());
+            [edge, edge.twin()].into_iter().all(|e| {
+                e.next().vertices().1.position().is_none_or(|p| {
+                    let p = p.to_f64();
+                    (p0.x - p.x) * (p1.x - p.x) + (p0.y - p.y) * (p1.y - p.y) >= 0.0
+                })
+            })
+        })
+        .collect()
+}
+
+// Sorts `points` into an implicit k-d tree: the middle point of every slice splits the rest,
+// by x at even depths and by y at odd ones.
+fn kd_tree(points: &mut [Point], depth: usize) {
+    if points.len() <= 1 {
+        return;
+    }
+
+    let middle = points.len() / 2;
+    let axis = |p: &Point| if depth.is_multiple_of(2) { p.x } else { p.y };
+    points.select_nth_unstable_by(middle, |a, b| axis(a).total_cmp(&axis(b)));
+    let (left, right) = points.split_at_mut(middle);
+    kd_tree(left, depth + 1);
+    kd_tree(&mut right[1..], depth + 1);
+}
+
+// Whether a point of the tree is strictly closer than `square_length` to both `p0` and `p1`,
+// skipping the subtrees whose bounds, as `[min_x, min_y, max_x, max_y]`, are too far.
+fn in_lune(
+    points: &[Point],
+    depth: usize,
+    bounds: [f64; 4],
+    p0: &Point,
+    p1: &Point,
+    square_length: f64,
+) -> bool {
+    let square_distance = |p: &Point| {
+        let dx = (bounds[0] - p.x).max(p.x - bounds[2]).max(0.0);
+        let dy = (bounds[1] - p.y).max(p.y - bounds[3]).max(0.0);
+        dx * dx + dy * dy
+    };
+    if points.is_empty()
+        || square_distance(p0) >= square_length
+        || square_distance(p1) >= square_length
+    {
+        return false;
+    }
+
+    let middle = points.len() / 2;
+    let point = &points[middle];
+    if point.distance_squared(p0) < square_length && point.distance_squared(p1) < square_length {
+        return true;
+    }
+
+    let (mut low, mut high) = (bounds, bounds);
+    if depth.is_multiple_of(2) {
+        (low[2], high[0]) = (point.x, point.x);
+    } else {
+        (low[3], high[1]) = (point.y, point.y);
+    }
+    in_lune(&points[..middle], depth + 1, low, p0, p1, square_length)
+        || in_lune(
+            &points[middle + 1..],
+            depth + 1,
+            high,
+            p0,
+            p1,
+            square_length,
+        )
+}
+
+/// Relative neighborhood graph: edges with no other point closer to both endpoints than they
+/// are to each other.
+pub fn relative_neighborhood<S: Scalar>(graph: &Graph<S>) -> Vec<Edge<'_, S>> {
+    // The points closer to both endpoints are not always next to them in the triangulation,
+    // so they are looked for in a k-d tree. Every such graph is a subgraph of the Urquhart
+    // graph, which leaves fewer edges to test.
+    let mut points = graph
+        .vertices()
+        .filter_map(|v| Some(v.position()?.to_f64()))
+        .collect::<Vec<_>>();
+    kd_tree(&mut points, 0);
+    let bounds = [
+        f64::NEG_INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        f64::INFINITY,
+    ];
+
+    urquhart(graph)
+        .into_iter()
+        .filter(|&edge| {
+            let (p0, p1) = endpoints(edge).unwrap();
+            let (p0, p1) = (p0.to_f64(), p1.to_f64());
+            !in_lune(&points, 0, bounds, &p0, &p1, p0.distance_squared(&p1))
+        })
+        .collect()
+}
+
+/// Delaunay edges minus the longest edge of every triangle. Triangles without a strictly
+/// longest edge keep all of them.
+pub fn urquhart<S: Scalar>(graph: &Graph<S>) -> Vec<Edge<'_, S>> {
+    let mut removed = vec![false; graph.edge_count()];
+    for face in graph.faces().filter(|face| !face.is_infinite()) {
+        let mut lengths = face.edges().filter_map(|edge| {
+            let (p0, p1) = endpoints(edge)?;
+            Some((p0.distance_squared(&p1), edge.id()))
+        });
+        let (Some(l0), Some(l1), Some(l2)) = (lengths.next(), lengths.next(), lengths.next())
+        else {
+            continue;
+        };
+
+        for (longest, others) in [(l0, [l1, l2]), (l1, [l0, l2]), (l2, [l0, l1])] {
+            if others.iter().all(|other| longest.0 > other.0) {
+                removed[longest.1] = true;
+            }
+        }
+    }
+
+    finite_edges(graph)
+        .filter(|edge| !removed[edge.id()])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, testing::Random};
+
+    #[test]
+    fn relative_neighborhood_has_empty_lunes() {
+        let mut random = Random::new(0x853c_49e6_748f_ea9b);
+        // Points along a noisy parabola give long edges whose lunes hold points that are not
+        // next to either endpoint.
+        let points = (0..400)
+            .map(|i| {
+                let t = random.float();
+                if i % 2 == 0 {
+                    Point {
+                        x: t,
+                        y: random.float(),
+                    }
+                } else {
+                    Point {
+                        x: t,
+                        y: t * t + 0.01 * random.float(),
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        let delaunay = Delaunay::from(points.clone().into()).unwrap();
+
+        let mut expected = finite_edges(&delaunay)
+            .filter(|&edge| {
+                let (p0, p1) = endpoints(edge).unwrap();
+                let length = p0.distance_squared(&p1);
+                !points
+                    .iter()
+                    .any(|p| p0.distance_squared(p) < length && p1.distance_squared(p) < length)
+            })
+            .map(|edge| edge.id())
+            .collect::<Vec<_>>();
+        let mut found = relative_neighborhood(&delaunay)
+            .into_iter()
+            .map(|edge| edge.id())
+            .collect::<Vec<_>>();
+        expected.sort_unstable();
+        found.sort_unstable();
+        assert_eq!(found, expected);
+    }
+}
@@ -0,0 +1,27 @@
+use core::cmp::Ordering;
+
+/// Entry of a `BinaryHeap` used as a priority queue, popping the lowest `cost` first.
+pub(crate) struct Open {
+    pub(crate) cost: f64,
+    pub(crate) index: usize,
+}
+
+impl PartialEq for Open {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
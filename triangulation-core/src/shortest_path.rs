@@ -0,0 +1,277 @@
+use crate::{Edge, Graph, Scalar, Vertex, analysis::Summary, queue::Open};
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+
+/// Length of `edge`, the default weight. Use `|e| *e.attribute::<f64>("weight").unwrap()` or
+/// any other function of the edge for user weights, an infinite weight closes the edge.
+pub fn euclidean<S: Scalar>(edge: Edge<'_, S>) -> f64 {
+    let (v0, v1) = edge.vertices();
+    match (v0.position(), v1.position()) {
+        (Some(p0), Some(p1)) => p0.distance(&p1),
+        _ => f64::INFINITY,
+    }
+}
+
+/// Distances from one source to every vertex, with the tree of shortest paths.
+#[derive(Debug)]
+pub struct ShortestPaths<'a, S = f64> {
+    graph: &'a Graph<S>,
+    distances: Vec<f64>,
+    previous: Vec<Option<usize>>,
+}
+
+impl<'a, S: Scalar> ShortestPaths<'a, S> {
+    /// Infinite for vertices that can't be reached.
+    pub fn distance(&self, vertex: Vertex<'_, S>) -> f64 {
+        self.distances[vertex.id()]
+    }
+
+    /// Distances by vertex id.
+    pub fn distances(&self) -> &[f64] {
+        &self.distances
+    }
+
+    /// Vertices from the source to `target`, None if it can't be reached.
+    pub fn path(&self, target: Vertex<'_, S>) -> Option<Vec<Vertex<'a, S>>> {
+        self.distances[target.id()].is_finite().then(|| {
+            let mut path = vec![self.graph.vertex(target.id())];
+            while let Some(v) = path.last().and_then(|v| self.previous[v.id()]) {
+                path.push(self.graph.vertex(v));
+            }
+            path.reverse();
+            path
+        })
+    }
+}
+
+// Dijkstra's algorithm, or A* with a heuristic, stopping at `target` when there is one. Edges
+// to the infinite vertex are never taken.
+fn search<'a, S, F, H>(
+    graph: &'a Graph<S>,
+    source: Vertex<'_, S>,
+    target: Option<Vertex<'_, S>>,
+    mut weight: F,
+    heuristic: H,
+) -> ShortestPaths<'a, S>
+where
+    S: Scalar,
+    F: FnMut(Edge<'a, S>) -> f64,
+    H: Fn(Vertex<'a, S>) -> f64,
+{
+    let mut distances = vec![f64::INFINITY; graph.vertex_count()];
+    let mut previous = vec![None; graph.vertex_count()];
+    let mut open = BinaryHeap::new();
+
+    let source = graph.vertex(source.id());
+    distances[source.id()] = 0.0;
+    open.push(Open {
+        cost: heuristic(source),
+        index: source.id(),
+    });
+
+    while let Some(Open { cost, index }) = open.pop() {
+        let vertex = graph.vertex(index);
+        if target.is_some_and(|t| t.id() == index) {
+            break;
+        }
+        if cost > distances[index] + heuristic(vertex) {
+            continue;
+        }
+
+        for edge in vertex.edges() {
+            let neighbor = edge.vertices().1;
+            if neighbor.position().is_none() {
+                continue;
+            }
+
+            let distance = distances[index] + weight(edge);
+            if distance < distances[neighbor.id()] {
+                distances[neighbor.id()] = distance;
+                previous[neighbor.id()] = Some(index);
+                open.push(Open {
+                    cost: distance + heuristic(neighbor),
+                    index: neighbor.id(),
+                });
+            }
+        }
+    }
+
+    ShortestPaths {
+        graph,
+        distances,
+        previous,
+    }
+}
+
+/// Shortest paths from `source` to every vertex along the edges of `graph`.
+pub fn dijkstra<'a, S, F>(
+    graph: &'a Graph<S>,
+    source: Vertex<'_, S>,
+    weight: F,
+) -> ShortestPaths<'a, S>
+where
+    S: Scalar,
+    F: FnMut(Edge<'a, S>) -> f64,
+{
+    search(graph, source, None, weight, |_| 0.0)
+}
+
+/// Shortest path from `source` to `target` with its length, guided by the straight line
+/// distance to `target`. The path is the shortest one as long as no edge weighs less than its
+/// length, as with `euclidean`.
+pub fn a_star<'a, S, F>(
+    graph: &'a Graph<S>,
+    source: Vertex<'_, S>,
+    target: Vertex<'_, S>,
+    weight: F,
+) -> Option<(f64, Vec<Vertex<'a, S>>)>
+where
+    S: Scalar,
+    F: FnMut(Edge<'a, S>) -> f64,
+{
+    let goal = target.position()?;
+    let paths = search(graph, source, Some(target), weight, |v| {
+        v.position().map_or(0.0, |p| p.distance(&goal))
+    });
+    Some((paths.distance(target), paths.path(target)?))
+}
+
+/// How much longer paths along the edges are than straight lines.
+#[derive(Debug, Clone, Copy)]
+pub struct Stretch<'a, S = f64> {
+    /// Graph distance over Euclidean distance, for every pair of finite vertices.
+    pub ratio: Summary,
+    /// A pair with the largest ratio, which is the stretch factor of the graph.
+    pub worst: Option<(Vertex<'a, S>, Vertex<'a, S>)>,
+}
+
+/// Stretch of the graph with Euclidean edge weights, from one search per vertex. The ratios
+/// are summarized as they come, in constant memory.
+pub fn stretch<S: Scalar>(graph: &Graph<S>) -> Stretch<'_, S> {
+    let mut worst = None;
+    let mut max = 0.0;
+
+    let ratios = graph.vertices().flat_map(|source| {
+        let paths = source
+            .position()
+            .map(|p0| (p0, dijkstra(graph, source, euclidean)));
+
+        graph
+            .vertices()
+            .skip(source.id() + 1)
+            .filter_map(move |target| {
+                let (p0, paths) = paths.as_ref()?;
+                let length = p0.distance(&target.position()?);
+                (length != 0.0).then(|| (paths.distance(target) / length, source, target))
+            })
+    });
+    let ratio = Summary::new(ratios.map(|(ratio, source, target)| {
+        if ratio > max {
+            max = ratio;
+            worst = Some((source, target));
+        }
+        ratio
+    }));
+
+    Stretch { ratio, worst }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Delaunay, Point, math, testing::Random};
+    use alloc::boxed::Box;
+
+    // Two triangles on either side of the edge from (0, 0) to (4, 0), which is flipped away.
+    fn kite() -> Delaunay {
+        let points = [(0.0, 0.0), (4.0, 0.0), (2.0, 1.0), (2.0, -1.0)].map(|(x, y)| Point { x, y });
+        Delaunay::from(Box::new(points)).unwrap()
+    }
+
+    fn vertex(graph: &Graph, index: usize) -> Vertex<'_> {
+        graph
+            .vertices()
+            .find(|v| v.point_index() == Some(index))
+            .unwrap()
+    }
+
+    #[test]
+    fn kite_paths() {
+        let delaunay = kite();
+        let side = math::sqrt(5.0);
+
+        let paths = dijkstra(&delaunay, vertex(&delaunay, 0), euclidean);
+        let distances = [0.0, 2.0 * side, side, side];
+        for (index, distance) in distances.into_iter().enumerate() {
+            assert!((paths.distance(vertex(&delaunay, index)) - distance).abs() < 1e-12);
+        }
+        assert_eq!(paths.distance(delaunay.vertex(0)), f64::INFINITY);
+
+        let (length, path) = a_star(
+            &delaunay,
+            vertex(&delaunay, 0),
+            vertex(&delaunay, 1),
+            euclidean,
+        )
+        .unwrap();
+        assert!((length - 2.0 * side).abs() < 1e-12);
+        let path = path
+            .iter()
+            .map(|v| v.point_index().unwrap())
+            .collect::<Vec<_>>();
+        assert!(path == [0, 2, 1] || path == [0, 3, 1]);
+
+        // An infinite weight closes the edges from the third point.
+        let closed = |edge: Edge<'_>| {
+            let (v0, v1) = edge.vertices();
+            if [v0.point_index(), v1.point_index()].contains(&Some(2)) {
+                f64::INFINITY
+            } else {
+                euclidean(edge)
+            }
+        };
+        let (_, path) = a_star(
+            &delaunay,
+            vertex(&delaunay, 0),
+            vertex(&delaunay, 1),
+            closed,
+        )
+        .unwrap();
+        let path = path
+            .iter()
+            .map(|v| v.point_index().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(path, [0, 3, 1]);
+    }
+
+    #[test]
+    fn kite_stretch() {
+        let delaunay = kite();
+        let stretch = stretch(&delaunay);
+
+        // Five pairs along an edge and the two ends of the flipped edge.
+        let worst = math::sqrt(5.0) / 2.0;
+        assert!((stretch.ratio.max - worst).abs() < 1e-12);
+        assert!((stretch.ratio.min - 1.0).abs() < 1e-12);
+        assert!((stretch.ratio.mean - (5.0 + worst) / 6.0).abs() < 1e-12);
+        let (v0, v1) = stretch.worst.unwrap();
+        let mut pair = [v0.point_index().unwrap(), v1.point_index().unwrap()];
+        pair.sort();
+        assert_eq!(pair, [0, 1]);
+    }
+
+    #[test]
+    fn random_stretch() {
+        let points = Random::new(0x5be0_cd19_137e_2179).points(100);
+        let delaunay = Delaunay::from(points.into()).unwrap();
+        let stretch = stretch(&delaunay);
+
+        // Delaunay triangulations are spanners with a stretch below 2.
+        assert!(stretch.ratio.min >= 1.0 - 1e-12);
+        assert!(stretch.ratio.max < 2.0);
+        assert!(stretch.ratio.mean >= stretch.ratio.min && stretch.ratio.mean <= stretch.ratio.max);
+        let (v0, v1) = stretch.worst.unwrap();
+        let paths = dijkstra(&delaunay, v0, euclidean);
+        let ratio = paths.distance(v1) / v0.position().unwrap().distance(&v1.position().unwrap());
+        assert_eq!(ratio, stretch.ratio.max);
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{Delaunay, Error, Face, Graph, Point, Vertex, math};
+use alloc::{boxed::Box, vec::Vec};
+use core::{f64::consts::PI, fmt, ops::Deref};
+
+pub type Vector = [f64; 3];
+
+fn dot(a: &Vector, b: &Vector) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: &Vector, b: &Vector) -> Vector {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(a: &Vector) -> Vector {
+    let length = math::sqrt(dot(a, a));
+    [a[0] / length, a[1] / length, a[2] / length]
+}
+
+// Signed area of the geodesic triangle, positive when counterclockwise seen from outside.
+fn triangle_area(a: &Vector, b: &Vector, c: &Vector) -> f64 {
+    2.0 * math::atan2(
+        dot(a, &cross(b, c)),
+        1.0 + dot(a, b) + dot(b, c) + dot(c, a),
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SphericalError {
+    Triangulation(Error),
+    /// A point is zero or not finite, and has no direction.
+    InvalidPoint,
+    /// A point is the same as the first one, which the others are projected from.
+    DuplicatePole,
+}
+
+impl fmt::Display for SphericalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SphericalError::Triangulation(error) => error.fmt(f),
+            SphericalError::InvalidPoint => write!(f, "points must be finite and non-zero"),
+            SphericalError::DuplicatePole => write!(f, "a point is the same as the first one"),
+        }
+    }
+}
+
+impl core::error::Error for SphericalError {}
+
+impl From<Error> for SphericalError {
+    fn from(error: Error) -> Self {
+        SphericalError::Triangulation(error)
+    }
+}
+
+/// Delaunay triangulation of points on the unit sphere.
+///
+/// The other points are projected stereographically from the first one, which makes their
+/// planar Delaunay triangulation the spherical one, with the infinite vertex standing for the
+/// first point. Use `position` and `point_index` rather than the planar ones of `Vertex`.
+#[derive(Debug)]
+pub struct Spherical {
+    delaunay: Delaunay,
+    points: Box<[Vector]>,
+}
+
+impl Deref for Spherical {
+    type Target = Graph;
+
+    fn deref(&self) -> &Self::Target {
+        &self.delaunay
+    }
+}
+
+impl Spherical {
+    /// Triangulates unit vectors, normalizing them first.
+    pub fn from(points: &[Vector]) -> Result<Self, SphericalError> {
+        let points = points.iter().map(normalize).collect::<Box<[_]>>();
+        if points.iter().flatten().any(|c| !c.is_finite()) {
+            return Err(SphericalError::InvalidPoint);
+        }
+        let pole = points.first().ok_or(Error::NotEnoughPoints)?;
+
+        let axis = if pole[0].abs() < 0.5 {
+            [1.0, 0.0, 0.0]
+        } else {
+            [0.0, 1.0, 0.0]
+        };
+        let u = normalize(&cross(&axis, pole));
+        let v = cross(pole, &u);
+
+        let delaunay = points[1..]
+            .iter()
+            .map(|p| {
+                let scale = 1.0 - dot(p, pole);
+                let projected = Point {
+                    x: dot(p, &u) / scale,
+                    y: dot(p, &v) / scale,
+                };
+                (projected.x.is_finite() && projected.y.is_finite())
+                    .then_some(projected)
+                    .ok_or(SphericalError::DuplicatePole)
+            })
+            .collect::<Result<Box<[_]>, _>>()?;
+
+        Ok(Spherical {
+            delaunay: Delaunay::from(delaunay)?,
+            points,
+        })
+    }
+
+    /// Triangulates longitudes and latitudes in degrees, as `x` and `y`.
+    pub fn from_lon_lat(points: &[Point]) -> Result<Self, SphericalError> {
+        let points = points
+            .iter()
+            .map(|p| {
+                let (lon, lat) = (p.x.to_radians(), p.y.to_radians());
+                [
+                    math::cos(lat) * math::cos(lon),
+                    math::cos(lat) * math::sin(lon),
+                    math::sin(lat),
+                ]
+            })
+            .collect::<Vec<_>>();
+        Spherical::from(&points)
+    }
+
+    pub fn points(&self) -> &[Vector] {
+        &self.points
+    }
+
+    pub fn point_index(&self, vertex: Vertex<'_>) -> usize {
+        vertex.point_index().map_or(0, |i| i + 1)
+    }
+
+    pub fn position(&self, vertex: Vertex<'_>) -> Vector {
+        self.points[self.point_index(vertex)]
+    }
+
+    /// Point indices of every triangle, counterclockwise seen from outside the sphere.
+    pub fn triangles(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.faces().map(|face| {
+            let mut vertices = face.vertices().map(|v| self.point_index(v));
+            [
+                vertices.next().unwrap(),
+                vertices.next().unwrap(),
+                vertices.next().unwrap(),
+            ]
+        })
+    }
+
+    fn corners(&self, face: Face<'_>) -> [Vector; 3] {
+        let mut vertices = face.vertices().map(|v| self.position(v));
+        [
+            vertices.next().unwrap(),
+            vertices.next().unwrap(),
+            vertices.next().unwrap(),
+        ]
+    }
+
+    /// Center of the empty circle through the three vertices of `face`.
+    pub fn circumcenter(&self, face: Face<'_>) -> Vector {
+        // The projection mirrors the plane, so planar faces are counterclockwise seen from
+        // outside and the normal points away from the center of the sphere.
+        let [a, b, c] = self.corners(face);
+        let ab = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+        let ac = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+        normalize(&cross(&ab, &ac))
+    }
+
+    pub fn area(&self, face: Face<'_>) -> f64 {
+        let [a, b, c] = self.corners(face);
+        // Faces are counterclockwise, a negative area is that of a face covering more than a
+        // hemisphere measured from the other side.
+        let area = triangle_area(&a, &b, &c);
+        if area < 0.0 { area + 4.0 * PI } else { area }
+    }
+
+    /// Circumcenters of the faces around `vertex`, in order.
+    pub fn voronoi_cell(&self, vertex: Vertex<'_>) -> Vec<Vector> {
+        vertex.faces().map(|f| self.circumcenter(f)).collect()
+    }
+
+    /// Area of the Voronoi cell of `vertex`. The areas of all cells sum to 4π.
+    pub fn cell_area(&self, vertex: Vertex<'_>) -> f64 {
+        let site = self.position(vertex);
+        let cell = self.voronoi_cell(vertex);
+        (0..cell.len())
+            .map(|i| triangle_area(&site, &cell[i], &cell[(i + 1) % cell.len()]))
+            .sum::<f64>()
+            .abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(count: usize) -> Vec<Vector> {
+        let golden = PI * (3.0 - math::sqrt(5.0));
+        (0..count)
+            .map(|i| {
+                let z = 1.0 - 2.0 * (i as f64 + 0.5) / count as f64;
+                let r = math::sqrt(1.0 - z * z);
+                let theta = golden * i as f64;
+                [r * math::cos(theta), r * math::sin(theta), z]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn octahedron() {
+        let points = [
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [-1.0, 0.0, 0.0],
+            [0.0, -1.0, 0.0],
+            [0.0, 0.0, -1.0],
+        ];
+        let spherical = Spherical::from(&points).unwrap();
+
+        assert_eq!(spherical.faces().count(), 8);
+        for face in spherical.faces() {
+            assert!((spherical.area(face) - PI / 2.0).abs() < 1e-12);
+        }
+        for vertex in spherical.vertices() {
+            assert!((spherical.cell_area(vertex) - 2.0 * PI / 3.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn areas_sum_to_the_sphere() {
+        let spherical = Spherical::from(&fibonacci(500)).unwrap();
+
+        let faces = spherical.faces().map(|f| spherical.area(f)).sum::<f64>();
+        let cells = spherical
+            .vertices()
+            .map(|v| spherical.cell_area(v))
+            .sum::<f64>();
+        assert!((faces - 4.0 * PI).abs() < 1e-9, "faces {faces}");
+        assert!((cells - 4.0 * PI).abs() < 1e-9, "cells {cells}");
+    }
+
+    #[test]
+    fn invalid_points() {
+        let mut points = fibonacci(10);
+        points.push(points[0]);
+        assert_eq!(
+            Spherical::from(&points).unwrap_err(),
+            SphericalError::DuplicatePole
+        );
+
+        points.pop();
+        points.push([0.0; 3]);
+        assert_eq!(
+            Spherical::from(&points).unwrap_err(),
+            SphericalError::InvalidPoint
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use crate::Point;
+use alloc::vec::Vec;
+
+// Seeded generator for the tests, the same with or without the `rand` feature.
+pub(crate) struct Random(u64);
+
+impl Random {
+    pub(crate) fn new(seed: u64) -> Self {
+        Random(seed)
+    }
+
+    // Uniform in [0, 1).
+    pub(crate) fn float(&mut self) -> f64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Uniform in the unit square.
+    pub(crate) fn point(&mut self) -> Point {
+        Point {
+            x: self.float(),
+            y: self.float(),
+        }
+    }
+
+    pub(crate) fn points(&mut self, count: usize) -> Vec<Point> {
+        (0..count).map(|_| self.point()).collect()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::RngCore for Random {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.float();
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// The header is checked in, regenerate it with `TRIANGULATION_HEADER=1 cargo build`.
+fn main() {
+    println!("cargo:rerun-if-env-changed=TRIANGULATION_HEADER");
+    if std::env::var_os("TRIANGULATION_HEADER").is_none() {
+        return;
+    }
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    cbindgen::generate(&crate_dir)
+        .expect("Failed to generate C bindings")
+        .write_to_file(format!("{crate_dir}/include/triangulation.h"));
+}
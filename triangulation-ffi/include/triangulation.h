@@ -0,0 +1,129 @@
+#ifndef TRIANGULATION_H
+#define TRIANGULATION_H
+
+/* Generated by cbindgen from triangulation-ffi, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Outcome of `triangulation_new`.
+ */
+typedef enum TriangulationStatus {
+  TRIANGULATION_STATUS_OK,
+  TRIANGULATION_STATUS_NULL_COORDINATES,
+  TRIANGULATION_STATUS_NON_FINITE_COORDINATE,
+  TRIANGULATION_STATUS_NOT_ENOUGH_POINTS,
+  TRIANGULATION_STATUS_COLLINEAR,
+  /**
+   * A point index does not fit in 32 bits.
+   */
+  TRIANGULATION_STATUS_TOO_MANY_POINTS,
+  TRIANGULATION_STATUS_PANIC,
+} TriangulationStatus;
+
+typedef struct Triangulation Triangulation;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Triangulates `count` points read from `coords` as `x0, y0, x1, y1, ...`.
+ *
+ * Returns null when `coords` is null, when a coordinate is not finite, when the points
+ * cannot be triangulated or when there are more than 2³² of them, with the reason written to
+ * `status` unless it is null. The result must be released with `triangulation_free`.
+ *
+ * # Safety
+ *
+ * `coords` must point to `2 * count` readable doubles, `status` must be null or point to a
+ * writable `TriangulationStatus`.
+ */
+struct Triangulation *triangulation_new(const double *coords,
+                                        size_t count,
+                                        enum TriangulationStatus *status);
+
+/**
+ * Releases a triangulation created by `triangulation_new`.
+ *
+ * # Safety
+ *
+ * `triangulation` must be null or come from `triangulation_new`, and must not be used afterwards.
+ */
+void triangulation_free(struct Triangulation *triangulation);
+
+/**
+ * Number of triangles.
+ *
+ * # Safety
+ *
+ * `triangulation` must be null or come from `triangulation_new`.
+ */
+size_t triangulation_triangle_count(const struct Triangulation *triangulation);
+
+/**
+ * Number of edges between input points.
+ *
+ * # Safety
+ *
+ * `triangulation` must be null or come from `triangulation_new`.
+ */
+size_t triangulation_edge_count(const struct Triangulation *triangulation);
+
+/**
+ * Number of points on the convex hull.
+ *
+ * # Safety
+ *
+ * `triangulation` must be null or come from `triangulation_new`.
+ */
+size_t triangulation_hull_count(const struct Triangulation *triangulation);
+
+/**
+ * Copies three point indices per triangle into `triangles` and returns the number of
+ * indices written, at most `capacity`.
+ *
+ * # Safety
+ *
+ * `triangulation` must be null or come from `triangulation_new`, `triangles` must be null
+ * or point to `capacity` writable values.
+ */
+size_t triangulation_copy_triangles(const struct Triangulation *triangulation,
+                                    uint32_t *triangles,
+                                    size_t capacity);
+
+/**
+ * Copies two point indices per edge into `edges` and returns the number of indices
+ * written, at most `capacity`.
+ *
+ * # Safety
+ *
+ * `triangulation` must be null or come from `triangulation_new`, `edges` must be null
+ * or point to `capacity` writable values.
+ */
+size_t triangulation_copy_edges(const struct Triangulation *triangulation,
+                                uint32_t *edges,
+                                size_t capacity);
+
+/**
+ * Copies the point indices of the convex hull into `hull` and returns the number of
+ * indices written, at most `capacity`.
+ *
+ * # Safety
+ *
+ * `triangulation` must be null or come from `triangulation_new`, `hull` must be null
+ * or point to `capacity` writable values.
+ */
+size_t triangulation_copy_hull(const struct Triangulation *triangulation,
+                               uint32_t *hull,
+                               size_t capacity);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TRIANGULATION_H */
@@ -0,0 +1,224 @@
+use std::{panic, ptr, slice};
+use triangulation_core::{Delaunay, Error, Point, Vertex};
+
+/// Outcome of `triangulation_new`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriangulationStatus {
+    Ok,
+    NullCoordinates,
+    NonFiniteCoordinate,
+    NotEnoughPoints,
+    Collinear,
+    /// A point index does not fit in 32 bits.
+    TooManyPoints,
+    Panic,
+}
+
+impl From<Error> for TriangulationStatus {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::NotEnoughPoints => TriangulationStatus::NotEnoughPoints,
+            Error::Collinear => TriangulationStatus::Collinear,
+        }
+    }
+}
+
+pub struct Triangulation {
+    triangles: Vec<u32>,
+    edges: Vec<u32>,
+    hull: Vec<u32>,
+}
+
+fn index(vertex: Vertex<'_>) -> Option<Result<u32, TriangulationStatus>> {
+    vertex
+        .point_index()
+        .map(|i| u32::try_from(i).map_err(|_| TriangulationStatus::TooManyPoints))
+}
+
+impl Triangulation {
+    fn new(delaunay: &Delaunay) -> Result<Self, TriangulationStatus> {
+        let triangles = delaunay
+            .faces()
+            .filter(|f| !f.is_infinite())
+            .flat_map(|f| f.vertices())
+            .filter_map(index)
+            .collect::<Result<_, _>>()?;
+
+        let edges = delaunay
+            .edges()
+            .filter_map(|e| {
+                let (v0, v1) = e.vertices();
+                Some([index(v0)?, index(v1)?])
+            })
+            .flatten()
+            .collect::<Result<_, _>>()?;
+
+        let hull = delaunay
+            .hull()
+            .filter_map(index)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Triangulation {
+            triangles,
+            edges,
+            hull,
+        })
+    }
+}
+
+unsafe fn copy(source: &[u32], destination: *mut u32, capacity: usize) -> usize {
+    if destination.is_null() {
+        return 0;
+    }
+
+    let len = source.len().min(capacity);
+    unsafe { ptr::copy_nonoverlapping(source.as_ptr(), destination, len) };
+    len
+}
+
+/// Triangulates `count` points read from `coords` as `x0, y0, x1, y1, ...`.
+///
+/// Returns null when `coords` is null, when a coordinate is not finite, when the points
+/// cannot be triangulated or when there are more than 2³² of them, with the reason written to
+/// `status` unless it is null. The result must be released with `triangulation_free`.
+///
+/// # Safety
+///
+/// `coords` must point to `2 * count` readable doubles, `status` must be null or point to a
+/// writable `TriangulationStatus`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn triangulation_new(
+    coords: *const f64,
+    count: usize,
+    status: *mut TriangulationStatus,
+) -> *mut Triangulation {
+    let result = unsafe { triangulate(coords, count) };
+    if let Some(status) = unsafe { status.as_mut() } {
+        *status = result
+            .as_ref()
+            .err()
+            .copied()
+            .unwrap_or(TriangulationStatus::Ok);
+    }
+    result.map_or(ptr::null_mut(), |t| Box::into_raw(Box::new(t)))
+}
+
+unsafe fn triangulate(
+    coords: *const f64,
+    count: usize,
+) -> Result<Triangulation, TriangulationStatus> {
+    if coords.is_null() {
+        return Err(TriangulationStatus::NullCoordinates);
+    }
+    let len = count
+        .checked_mul(2)
+        .ok_or(TriangulationStatus::TooManyPoints)?;
+
+    let coords = unsafe { slice::from_raw_parts(coords, len) };
+    if !coords.iter().all(|c| c.is_finite()) {
+        return Err(TriangulationStatus::NonFiniteCoordinate);
+    }
+    let points = coords
+        .chunks_exact(2)
+        .map(|c| Point { x: c[0], y: c[1] })
+        .collect::<Vec<_>>()
+        .into_boxed_slice();
+
+    // A panic must not unwind into the caller.
+    panic::catch_unwind(|| Triangulation::new(&Delaunay::from(points)?))
+        .unwrap_or(Err(TriangulationStatus::Panic))
+}
+
+/// Releases a triangulation created by `triangulation_new`.
+///
+/// # Safety
+///
+/// `triangulation` must be null or come from `triangulation_new`, and must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn triangulation_free(triangulation: *mut Triangulation) {
+    if !triangulation.is_null() {
+        drop(unsafe { Box::from_raw(triangulation) });
+    }
+}
+
+/// Number of triangles.
+///
+/// # Safety
+///
+/// `triangulation` must be null or come from `triangulation_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn triangulation_triangle_count(
+    triangulation: *const Triangulation,
+) -> usize {
+    unsafe { triangulation.as_ref() }.map_or(0, |t| t.triangles.len() / 3)
+}
+
+/// Number of edges between input points.
+///
+/// # Safety
+///
+/// `triangulation` must be null or come from `triangulation_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn triangulation_edge_count(triangulation: *const Triangulation) -> usize {
+    unsafe { triangulation.as_ref() }.map_or(0, |t| t.edges.len() / 2)
+}
+
+/// Number of points on the convex hull.
+///
+/// # Safety
+///
+/// `triangulation` must be null or come from `triangulation_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn triangulation_hull_count(triangulation: *const Triangulation) -> usize {
+    unsafe { triangulation.as_ref() }.map_or(0, |t| t.hull.len())
+}
+
+/// Copies three point indices per triangle into `triangles` and returns the number of
+/// indices written, at most `capacity`.
+///
+/// # Safety
+///
+/// `triangulation` must be null or come from `triangulation_new`, `triangles` must be null
+/// or point to `capacity` writable values.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn triangulation_copy_triangles(
+    triangulation: *const Triangulation,
+    triangles: *mut u32,
+    capacity: usize,
+) -> usize {
+    unsafe { triangulation.as_ref() }
+        .map_or(0, |t| unsafe { copy(&t.triangles, triangles, capacity) })
+}
+
+/// Copies two point indices per edge into `edges` and returns the number of indices
+/// written, at most `capacity`.
+///
+/// # Safety
+///
+/// `triangulation` must be null or come from `triangulation_new`, `edges` must be null
+/// or point to `capacity` writable values.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn triangulation_copy_edges(
+    triangulation: *const Triangulation,
+    edges: *mut u32,
+    capacity: usize,
+) -> usize {
+    unsafe { triangulation.as_ref() }.map_or(0, |t| unsafe { copy(&t.edges, edges, capacity) })
+}
+
+/// Copies the point indices of the convex hull into `hull` and returns the number of
+/// indices written, at most `capacity`.
+///
+/// # Safety
+///
+/// `triangulation` must be null or come from `triangulation_new`, `hull` must be null
+/// or point to `capacity` writable values.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn triangulation_copy_hull(
+    triangulation: *const Triangulation,
+    hull: *mut u32,
+    capacity: usize,
+) -> usize {
+    unsafe { triangulation.as_ref() }.map_or(0, |t| unsafe { copy(&t.hull, hull, capacity) })
+}
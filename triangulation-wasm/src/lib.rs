@@ -0,0 +1,168 @@
+use js_sys::{Float64Array, Int32Array, Uint32Array};
+use triangulation_core::{Delaunay, Point};
+use wasm_bindgen::prelude::*;
+
+// Arrays are computed once, getters copy them straight into new JavaScript typed arrays.
+#[wasm_bindgen]
+pub struct Triangulation {
+    delaunay: Delaunay,
+    triangles: Box<[u32]>,
+    halfedges: Box<[i32]>,
+    hull: Box<[u32]>,
+    circumcenters: Box<[f64]>,
+}
+
+#[wasm_bindgen]
+pub struct VoronoiCells {
+    offsets: Box<[u32]>,
+    coords: Box<[f64]>,
+}
+
+#[wasm_bindgen]
+impl VoronoiCells {
+    /// Cell `i` is made of the points `offsets[i]..offsets[i + 1]` of `coords`.
+    #[wasm_bindgen(getter)]
+    pub fn offsets(&self) -> Uint32Array {
+        Uint32Array::from(&self.offsets[..])
+    }
+
+    /// Flat `[x0, y0, x1, y1, ...]` polygon vertices of every cell.
+    #[wasm_bindgen(getter)]
+    pub fn coords(&self) -> Float64Array {
+        Float64Array::from(&self.coords[..])
+    }
+}
+
+// Twin of every half-edge of `triangles`, as returned by `Triangulation::halfedges`.
+fn halfedges(delaunay: &Delaunay, faces: &[usize], triangles: &[u32]) -> Box<[i32]> {
+    let mut triangle_of_face = vec![None; delaunay.face_count()];
+    for (t, &f) in faces.iter().enumerate() {
+        triangle_of_face[f] = Some(t);
+    }
+
+    faces
+        .iter()
+        .flat_map(|&f| delaunay.face(f).edges())
+        .map(|edge| {
+            let twin = edge.twin();
+            let origin = twin.vertices().0.point_index();
+
+            triangle_of_face[twin.face().id()]
+                .and_then(|t| {
+                    (0..3)
+                        .map(|k| 3 * t + k)
+                        .find(|&i| Some(triangles[i] as usize) == origin)
+                })
+                .map_or(-1, |i| i as i32)
+        })
+        .collect()
+}
+
+#[wasm_bindgen]
+impl Triangulation {
+    /// Triangulates the flat `[x0, y0, x1, y1, ...]` coordinates.
+    #[wasm_bindgen(constructor)]
+    pub fn new(coords: &[f64]) -> Result<Triangulation, JsError> {
+        if !coords.len().is_multiple_of(2) {
+            return Err(JsError::new("Odd number of coordinates"));
+        }
+        if !coords.iter().all(|c| c.is_finite()) {
+            return Err(JsError::new("Coordinates must be finite"));
+        }
+
+        let points = coords
+            .chunks_exact(2)
+            .map(|c| Point { x: c[0], y: c[1] })
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+        let delaunay = Delaunay::from(points)?;
+
+        let mut triangles = Vec::with_capacity(delaunay.face_count() * 3);
+        let mut faces = Vec::with_capacity(delaunay.face_count());
+
+        for face in delaunay.faces().filter(|f| !f.is_infinite()) {
+            faces.push(face.id());
+            triangles.extend(
+                face.vertices()
+                    .filter_map(|v| v.point_index())
+                    .map(|i| i as u32),
+            );
+        }
+
+        let halfedges = halfedges(&delaunay, &faces, &triangles);
+        let hull = delaunay
+            .hull()
+            .filter_map(|v| v.point_index())
+            .map(|i| i as u32)
+            .collect();
+        let circumcenters = faces
+            .iter()
+            .filter_map(|&f| delaunay.face(f).circumcenter())
+            .flat_map(|p| [p.x, p.y])
+            .collect();
+
+        Ok(Triangulation {
+            delaunay,
+            triangles: triangles.into_boxed_slice(),
+            halfedges,
+            hull,
+            circumcenters,
+        })
+    }
+
+    /// Point indices of every triangle, three per triangle.
+    #[wasm_bindgen(getter)]
+    pub fn triangles(&self) -> Uint32Array {
+        Uint32Array::from(&self.triangles[..])
+    }
+
+    /// For the half-edge going from `triangles[i]` to the next corner of its triangle,
+    /// the index of its twin half-edge, or `-1` on the hull.
+    #[wasm_bindgen(getter)]
+    pub fn halfedges(&self) -> Int32Array {
+        Int32Array::from(&self.halfedges[..])
+    }
+
+    /// Point indices of the convex hull.
+    #[wasm_bindgen(getter)]
+    pub fn hull(&self) -> Uint32Array {
+        Uint32Array::from(&self.hull[..])
+    }
+
+    /// Circumcenter of every triangle, two coordinates per triangle.
+    #[wasm_bindgen(getter)]
+    pub fn circumcenters(&self) -> Float64Array {
+        Float64Array::from(&self.circumcenters[..])
+    }
+
+    /// Voronoi cell of every input point, clipped to the given bounds.
+    #[wasm_bindgen(js_name = voronoiCells)]
+    pub fn voronoi_cells(&self, xmin: f64, ymin: f64, xmax: f64, ymax: f64) -> VoronoiCells {
+        let bounds = [
+            Point { x: xmin, y: ymin },
+            Point { x: xmax, y: ymin },
+            Point { x: xmax, y: ymax },
+            Point { x: xmin, y: ymax },
+        ];
+
+        let mut cells = vec![Vec::new(); self.delaunay.points().len()];
+        for vertex in self.delaunay.vertices() {
+            if let Some(i) = vertex.point_index() {
+                cells[i] = vertex.clipped_voronoi_cell(&bounds);
+            }
+        }
+
+        let mut offsets = Vec::with_capacity(cells.len() + 1);
+        let mut coords = Vec::new();
+        offsets.push(0);
+        for cell in cells {
+            coords.extend(cell.iter().flat_map(|p| [p.x, p.y]));
+            offsets.push((coords.len() / 2) as u32);
+        }
+
+        VoronoiCells {
+            offsets: offsets.into_boxed_slice(),
+            coords: coords.into_boxed_slice(),
+        }
+    }
+}